@@ -0,0 +1,335 @@
+use crate::history::DEFAULT_THRESHOLD;
+use lib::{Day, Format, Input, Part, Registry};
+use std::{ops::RangeInclusive, path::PathBuf, time::Duration};
+
+pub const USAGE: &str = "\
+Usage: aoc [COMMAND] [DAYS] [OPTIONS]
+
+COMMAND:
+    run                   run the solutions (default)
+    check                 compare the solutions against the recorded answers
+    summary               run the days in parallel and summarize their results
+    bench                 benchmark parsing and both parts separately
+    history               show the saved benchmark results over time and flag regressions
+    scale                 benchmark the days with generated inputs of increasing size
+    variants              cross-check and benchmark all implementations of each part
+    fuzz                  feed mutations of the example inputs to the parsers of the
+                          days and report the panics with minimized inputs
+    report                write an HTML page with the answers, timings and renders of the days
+    examples              extract the examples of challenge.txt into example fixtures
+    submit                solve a part of a day and submit the answer, by default the
+                          first unsolved part of the latest day
+    fetch                 download the puzzle input of the days into input.txt
+    watch                 rerun a day with its examples and real input whenever its
+                          sources or inputs change
+    doctor                check the consistency of the whole workspace
+    new                   create the crate of a day (defaults to the day after the latest)
+
+DAYS:
+    <n>                   a single day, e.g. `5`
+    <a>..<b>              a range of days, e.g. `1..5` (also `1-5`)
+    all                   all days
+                          (defaults to the latest day, or all days for
+                          `check`, `summary`, `history`, `scale`, `report`,
+                          `variants` and `fuzz`)
+
+OPTIONS:
+    -p, --part <n>        only run part 1 or 2
+    -t, --test            only run with test_input.txt
+        --input <path>    run with the given input file instead
+        --stdin           run with the input read from stdin instead
+        --inputs <dir>    `check` every input file in the given directory against
+                          its answers.txt instead, also from a day-XX subdirectory
+    -f, --format <fmt>    output format of `run`: text, json or tsv
+        --timeout <secs>  stop running a day after the given number of seconds
+        --memory <MiB>    stop running a day holding more heap memory than given
+    -j, --jobs <n>        number of days `summary` runs at once
+                          (default: number of CPUs)
+    -o, --output <path>   file `report` writes to (default: report.html)
+        --trace <spec>    print the trace events of the days, e.g. `day14=debug`
+                          or `info` (release builds need `--features trace`)
+        --no-color        disable colored output
+    -h, --help            print this help
+
+BENCH, SCALE, VARIANTS AND FUZZ OPTIONS:
+    -i, --iterations <n>  number of measured iterations (default: 10)
+    -w, --warmup <n>      number of warmup iterations (default: 3)
+        --json            print the results as JSON
+        --alloc           also report the allocations and peak heap of each phase
+        --save            append the results of `bench` to bench_history.tsv
+        --threshold <pct> slowdown in percent flagged as regression by `history`
+                          (default: 10)
+        --sizes <list>    comma separated input sizes `scale` generates, e.g.
+                          `10,20,40` (default: depending on the day)
+        --seed <n>        seed of the inputs `scale` generates and `fuzz` mutates
+                          (default: 0)
+        --cases <n>       number of mutated inputs `fuzz` parses per day
+                          (default: 2000)";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Check,
+    Summary,
+    Bench,
+    History,
+    Scale,
+    Variants,
+    Fuzz,
+    Report,
+    Examples,
+    Fetch,
+    Submit,
+    Watch,
+    Doctor,
+    New,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Days {
+    Latest,
+    Range(RangeInclusive<u8>),
+    All,
+}
+
+impl Days {
+    fn parse(v: &str) -> Result<Self, String> {
+        if v == "all" {
+            return Ok(Self::All);
+        }
+
+        let parse_day = |d: &str| d.parse::<u8>().map_err(|_| format!("invalid day: {d}"));
+
+        let range = match v.split_once("..").or_else(|| v.split_once('-')) {
+            Some((from, to)) => parse_day(from)?..=parse_day(to)?,
+            None => {
+                let day = parse_day(v)?;
+                day..=day
+            }
+        };
+
+        Ok(Self::Range(range))
+    }
+
+    pub fn select<'a>(&self, registry: &'a Registry) -> Vec<&'a Day> {
+        match self {
+            Self::Latest => registry.last().into_iter().collect(),
+            Self::Range(range) => registry
+                .iter()
+                .filter(|d| range.contains(&d.number))
+                .collect(),
+            Self::All => registry.iter().collect(),
+        }
+    }
+
+    /// The numbers of the selected days. Unlike [`Days::select`], days of
+    /// a range which are not registered yet are included as well.
+    pub fn numbers(&self, registry: &Registry) -> Vec<u8> {
+        match self {
+            Self::Range(range) => range.clone().collect(),
+            _ => self.select(registry).iter().map(|d| d.number).collect(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Args {
+    pub command: Command,
+    pub days: Days,
+    pub part: Option<Part>,
+    pub test: bool,
+    pub color: bool,
+    pub help: bool,
+    pub iterations: usize,
+    pub warmup: usize,
+    pub json: bool,
+    pub save: bool,
+    pub alloc: bool,
+    pub threshold: f64,
+    pub format: Format,
+    pub input: Option<Input>,
+    pub timeout: Option<Duration>,
+    /// Memory limit in bytes.
+    pub memory: Option<usize>,
+    /// Set for the child processes running a single day within the limits.
+    pub worker: bool,
+    pub jobs: usize,
+    pub output: Option<PathBuf>,
+    /// Directory of additional inputs to check.
+    pub inputs: Option<PathBuf>,
+    /// Sizes of the generated inputs, overriding the ones of the days.
+    pub sizes: Option<Vec<usize>>,
+    pub seed: u64,
+    /// Number of mutated inputs to parse per day.
+    pub cases: usize,
+    /// Directives of the trace events to print.
+    pub trace: Option<String>,
+}
+
+impl Args {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut res = Self {
+            command: Command::Run,
+            days: Days::Latest,
+            part: None,
+            test: false,
+            color: std::env::var_os("NO_COLOR").is_none(),
+            help: false,
+            iterations: 10,
+            warmup: 3,
+            json: false,
+            save: false,
+            alloc: false,
+            threshold: DEFAULT_THRESHOLD,
+            format: Format::Text,
+            input: None,
+            timeout: None,
+            memory: None,
+            worker: false,
+            jobs: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            output: None,
+            inputs: None,
+            sizes: None,
+            seed: 0,
+            cases: 2000,
+            trace: None,
+        };
+
+        let number = |name: &str, v: Option<String>| {
+            let v = v.ok_or_else(|| format!("missing value for {name}"))?;
+            v.parse::<usize>()
+                .map_err(|_| format!("invalid value for {name}: {v}"))
+        };
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-p" | "--part" => {
+                    let part = args.next().ok_or("missing value for --part")?;
+                    res.part = Some(part.parse()?);
+                }
+                "-t" | "--test" => res.test = true,
+                "--input" => {
+                    let path = args.next().ok_or("missing value for --input")?;
+                    res.input = Some(Input::file(path));
+                }
+                "--stdin" => res.input = Some(Input::stdin()),
+                "--inputs" => {
+                    let path = args.next().ok_or("missing value for --inputs")?;
+                    res.inputs = Some(path.into());
+                }
+                "-o" | "--output" => {
+                    let path = args.next().ok_or("missing value for --output")?;
+                    res.output = Some(path.into());
+                }
+                "--trace" => {
+                    let spec = args.next().ok_or("missing value for --trace")?;
+                    res.trace = Some(spec);
+                }
+                "--no-color" => res.color = false,
+                "-i" | "--iterations" => {
+                    res.iterations = number("--iterations", args.next())?.max(1)
+                }
+                "-w" | "--warmup" => res.warmup = number("--warmup", args.next())?,
+                "--json" => res.json = true,
+                "--sizes" => {
+                    let v = args.next().ok_or("missing value for --sizes")?;
+                    let sizes = v
+                        .split(',')
+                        .map(|s| s.trim().parse::<usize>().ok().filter(|&s| s > 0))
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or_else(|| format!("invalid value for --sizes: {v}"))?;
+                    res.sizes = Some(sizes);
+                }
+                "--seed" => res.seed = number("--seed", args.next())? as u64,
+                "--cases" => res.cases = number("--cases", args.next())?,
+                "--save" => res.save = true,
+                "--alloc" => res.alloc = true,
+                "--threshold" => {
+                    let v = args.next().ok_or("missing value for --threshold")?;
+                    res.threshold = v
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|t| t.is_finite() && *t >= 0.0)
+                        .ok_or_else(|| format!("invalid value for --threshold: {v}"))?;
+                }
+                "--timeout" => {
+                    let v = args.next().ok_or("missing value for --timeout")?;
+                    let secs = v
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|s| s.is_finite() && *s > 0.0)
+                        .ok_or_else(|| format!("invalid value for --timeout: {v}"))?;
+                    res.timeout = Some(Duration::from_secs_f64(secs));
+                }
+                "--memory" => {
+                    let mib = number("--memory", args.next())?;
+                    let bytes = mib
+                        .checked_mul(1 << 20)
+                        .ok_or_else(|| format!("invalid value for --memory: {mib}"))?;
+                    res.memory = Some(bytes);
+                }
+                "--worker" => res.worker = true,
+                "-j" | "--jobs" => res.jobs = number("--jobs", args.next())?.max(1),
+                "-f" | "--format" => {
+                    let format = args.next().ok_or("missing value for --format")?;
+                    res.format = format.parse()?;
+                }
+                "-h" | "--help" => res.help = true,
+                "run" => res.command = Command::Run,
+                "check" => res.command = Command::Check,
+                "summary" => res.command = Command::Summary,
+                "bench" => res.command = Command::Bench,
+                "history" => res.command = Command::History,
+                "scale" => res.command = Command::Scale,
+                "variants" => res.command = Command::Variants,
+                "fuzz" => res.command = Command::Fuzz,
+                "report" => res.command = Command::Report,
+                "examples" => res.command = Command::Examples,
+                "fetch" => res.command = Command::Fetch,
+                "submit" => res.command = Command::Submit,
+                "watch" => res.command = Command::Watch,
+                "doctor" => res.command = Command::Doctor,
+                "new" => res.command = Command::New,
+                v if v.starts_with('-') => return Err(format!("unknown option: {v}")),
+                v => res.days = Days::parse(v)?,
+            }
+        }
+
+        if matches!(
+            res.command,
+            Command::Check
+                | Command::Summary
+                | Command::History
+                | Command::Scale
+                | Command::Report
+                | Command::Variants
+                | Command::Fuzz
+        ) && res.days == Days::Latest
+        {
+            res.days = Days::All;
+        }
+
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(Days::parse("all"), Ok(Days::All));
+        assert_eq!(Days::parse("5"), Ok(Days::Range(5..=5)));
+        assert_eq!(Days::parse("3..7"), Ok(Days::Range(3..=7)));
+        assert_eq!(Days::parse("3-7"), Ok(Days::Range(3..=7)));
+        assert!(Days::parse("x").is_err());
+    }
+
+    #[test]
+    fn test_parse_memory() {
+        let parse = |v: &str| Args::parse(["--memory", v].map(String::from).into_iter());
+        assert_eq!(parse("2").unwrap().memory, Some(2 << 20));
+        assert!(parse(&usize::MAX.to_string()).is_err());
+    }
+}
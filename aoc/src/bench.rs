@@ -0,0 +1,221 @@
+use crate::{args::Args, history, table::Table};
+use lib::{AllocStats, Day, Input, Part};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort();
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<Duration>().as_secs_f64() / n;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Self {
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+pub struct Measurement {
+    pub day: &'static str,
+    pub phase: &'static str,
+    pub stats: Stats,
+    /// Heap usage of a single run, if requested via `--alloc`.
+    pub alloc: Option<AllocStats>,
+}
+
+pub fn measure<T>(warmup: usize, iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let mut samples: Vec<_> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(&mut samples)
+}
+
+pub fn bench_day(day: &Day, input: &str, args: &Args) -> Vec<Measurement> {
+    let (warmup, iterations) = (args.warmup, args.iterations);
+    let mut res = vec![];
+
+    let stats = measure(warmup, iterations, || day.parse(input));
+    let (parsed, alloc) = day.parse_alloc(input);
+    res.push(Measurement {
+        day: day.name,
+        phase: "parse",
+        stats,
+        alloc: args.alloc.then_some(alloc),
+    });
+
+    for part in Part::ALL {
+        if args.part.is_some_and(|p| p != part) {
+            continue;
+        }
+
+        let stats = measure(warmup, iterations, || day.part(&parsed, part));
+        let alloc = args.alloc.then(|| day.part_alloc(&parsed, part).1);
+        res.push(Measurement {
+            day: day.name,
+            phase: match part {
+                Part::One => "part1",
+                Part::Two => "part2",
+            },
+            stats,
+            alloc,
+        });
+    }
+
+    res
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        b if b < 1 << 10 => format!("{b} B"),
+        b if b < 1 << 20 => format!("{:.1} KiB", b as f64 / (1 << 10) as f64),
+        b => format!("{:.1} MiB", b as f64 / (1 << 20) as f64),
+    }
+}
+
+fn print_table(measurements: &[Measurement], colored: bool) {
+    let mut header = vec!["DAY", "PHASE", "MEAN", "MEDIAN", "STDDEV", "MIN", "MAX"];
+    let alloc = measurements.iter().any(|m| m.alloc.is_some());
+    if alloc {
+        header.extend(["ALLOCS", "ALLOCATED", "PEAK"]);
+    }
+    let mut table = Table::new(&header);
+
+    for m in measurements {
+        let s = &m.stats;
+        let mut row = vec![
+            m.day.into(),
+            m.phase.into(),
+            format!("{:.2?}", s.mean),
+            format!("{:.2?}", s.median),
+            format!("{:.2?}", s.stddev),
+            format!("{:.2?}", s.min),
+            format!("{:.2?}", s.max),
+        ];
+        if let Some(a) = m.alloc {
+            row.extend([
+                a.allocations.to_string(),
+                format_bytes(a.bytes),
+                format_bytes(a.peak),
+            ]);
+        }
+        table.row(row);
+    }
+
+    table.print(colored);
+}
+
+fn print_json(measurements: &[Measurement], iterations: usize) {
+    let entries: Vec<_> = measurements
+        .iter()
+        .map(|m| {
+            let s = &m.stats;
+            let alloc = m.alloc.map_or(String::new(), |a| {
+                format!(
+                    ", \"allocations\": {}, \"allocated_bytes\": {}, \"peak_bytes\": {}",
+                    a.allocations, a.bytes, a.peak
+                )
+            });
+            format!(
+                "  {{\"day\": \"{}\", \"phase\": \"{}\", \"iterations\": {}, \
+                 \"mean_ns\": {}, \"median_ns\": {}, \"stddev_ns\": {}, \
+                 \"min_ns\": {}, \"max_ns\": {}{alloc}}}",
+                m.day,
+                m.phase,
+                iterations,
+                s.mean.as_nanos(),
+                s.median.as_nanos(),
+                s.stddev.as_nanos(),
+                s.min.as_nanos(),
+                s.max.as_nanos(),
+            )
+        })
+        .collect();
+
+    println!("[\n{}\n]", entries.join(",\n"));
+}
+
+pub fn bench(args: &Args, days: &[&Day]) {
+    let mut measurements = vec![];
+
+    for day in days {
+        let input = match Input::day_file(day, "input.txt").read() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{}: {err}", day.name);
+                continue;
+            }
+        };
+
+        measurements.extend(bench_day(day, &input, args));
+    }
+
+    if args.json {
+        print_json(&measurements, args.iterations);
+    } else {
+        print_table(&measurements, args.color);
+    }
+
+    if args.save {
+        let root = crate::root();
+        if let Err(err) = history::save(&root, &measurements) {
+            eprintln!(
+                "failed to save to {}: {err}",
+                history::file(&root).display()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(&mut [ms(4), ms(1), ms(3), ms(2)]);
+
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.max, ms(4));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    args::Args,
+    color, panic,
+    sandbox::{self, Status as SandboxStatus},
+    table::Table,
+};
+use lib::{Answers, Day, Input, Part};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Match,
+    Mismatch,
+    Missing,
+    /// Running the input panicked or violated the limits.
+    Failed,
+}
+
+impl Status {
+    fn label(self) -> (&'static str, &'static str) {
+        match self {
+            Status::Match => ("MATCH", color::GREEN),
+            Status::Mismatch => ("MISMATCH", color::RED),
+            Status::Missing => ("MISSING", color::YELLOW),
+            Status::Failed => ("FAILED", color::RED),
+        }
+    }
+}
+
+/// The directory holding the inputs to check a day with. Inputs passed
+/// via `--inputs` are expected in a `day-XX` subdirectory, or directly in
+/// the given directory if only a single day is checked.
+fn dir(args: &Args, day: &Day, single: bool) -> Option<PathBuf> {
+    let Some(inputs) = &args.inputs else {
+        return Some(day.dir());
+    };
+
+    let sub = inputs.join(day.name);
+    match sub.is_dir() {
+        true => Some(sub),
+        false => single.then(|| inputs.clone()),
+    }
+}
+
+/// Inputs to check for a day: every recorded one, plus the default
+/// inputs and examples so that unrecorded answers show up as missing.
+/// For a directory passed via `--inputs`, every file in it is checked.
+fn inputs(args: &Args, day: &Day, dir: &Path, answers: &Answers) -> Vec<String> {
+    let mut inputs: Vec<_> = answers.iter().map(|a| a.input.clone()).collect();
+
+    let defaults = match args.inputs {
+        Some(_) => input_files(dir),
+        None => {
+            let mut defaults = vec!["input.txt".to_owned()];
+            if dir.join("test_input.txt").exists() {
+                defaults.push("test_input.txt".into());
+            }
+            defaults.extend(Input::examples(day).iter().map(|i| i.name().to_owned()));
+            defaults
+        }
+    };
+
+    for default in defaults {
+        if !inputs.contains(&default) {
+            inputs.push(default);
+        }
+    }
+
+    inputs
+}
+
+fn input_files(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut files: Vec<_> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file())
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .filter(|f| f != Answers::FILE && !f.starts_with('.'))
+        .collect();
+    files.sort();
+    files
+}
+
+/// The answers of the parts solved for an input, and the error which kept
+/// the remaining parts from being solved.
+struct Solved {
+    answers: Vec<(Part, String)>,
+    error: Option<String>,
+}
+
+/// Solves the given parts of the input, isolated in a worker process if
+/// limits are set. Returns `None` if the input does not exist.
+fn solve(args: &Args, day: &Day, input: &Input, parts: &[Part]) -> Option<Solved> {
+    let raw = input.read().ok()?;
+    let mut answers = vec![];
+
+    if sandbox::enabled(args) {
+        let status = sandbox::run(args, day, Some(input), |res| {
+            answers.push((res.part, res.answer))
+        });
+        let error = match status {
+            SandboxStatus::Ok => None,
+            SandboxStatus::Panic(err) | SandboxStatus::Failed(err) => Some(err),
+            status => Some(status.to_string()),
+        };
+        return Some(Solved { answers, error });
+    }
+
+    let res = panic::catch(|| {
+        let parsed = day.parse(&raw);
+        for &part in parts {
+            answers.push((part, day.part(&parsed, part)));
+        }
+    });
+    let error = res.err().map(|panic| panic.describe(Some(&raw)));
+    Some(Solved { answers, error })
+}
+
+/// Runs the given days against their recorded answers and returns whether
+/// all of them matched.
+pub fn check(args: &Args, days: &[&Day]) -> bool {
+    let mut table = Table::new(&["DAY", "INPUT", "PART", "EXPECTED", "ACTUAL", "STATUS"]);
+    let mut failures = vec![];
+    let mut ok = true;
+
+    for day in days {
+        let Some(dir) = dir(args, day, days.len() == 1) else {
+            continue;
+        };
+        let answers = Answers::load(&dir);
+
+        for file in inputs(args, day, &dir, &answers) {
+            let answer = answers.get(&file);
+            let parts: Vec<_> = answers
+                .parts(&file)
+                .into_iter()
+                .filter(|&p| args.part.is_none_or(|part| part == p))
+                .collect();
+
+            let input = Input::file(dir.join(&file));
+            let solved = solve(args, day, &input, &parts);
+            let error = solved.as_ref().and_then(|s| s.error.as_ref());
+            if let Some(err) = error {
+                failures.push(format!("{} {file}: {err}", day.name));
+            }
+
+            for &part in &parts {
+                let expected = answer.and_then(|a| a.part(part));
+                let actual = solved.as_ref().and_then(|s| {
+                    s.answers
+                        .iter()
+                        .find(|(p, _)| *p == part)
+                        .map(|(_, a)| a.clone())
+                });
+
+                let status = match (expected, &actual) {
+                    (Some(e), Some(a)) if e == a => Status::Match,
+                    (Some(_), Some(_)) => Status::Mismatch,
+                    (_, None) if error.is_some() => Status::Failed,
+                    _ => Status::Missing,
+                };
+                ok &= matches!(status, Status::Match | Status::Missing);
+
+                let (label, c) = status.label();
+                table.row(vec![
+                    day.name.into(),
+                    file.clone(),
+                    part.to_string(),
+                    expected.unwrap_or("-").into(),
+                    actual.unwrap_or_else(|| "-".into()),
+                    label.into(),
+                ]);
+                table.color_last(c);
+            }
+        }
+    }
+
+    table.print(args.color);
+    for failure in &failures {
+        println!("\n{}", color::paint(failure, color::RED, args.color));
+    }
+
+    ok
+}
@@ -0,0 +1,273 @@
+use crate::{args::Args, table::Table};
+use lib::{Answers, Part, Registry};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+use toml_edit::DocumentMut;
+
+/// Files pinning the toolchain of the workspace for rustup.
+const TOOLCHAIN_FILES: &[&str] = &["rust-toolchain.toml", "rust-toolchain"];
+
+struct Problem {
+    krate: String,
+    check: &'static str,
+    detail: String,
+}
+
+fn day_dirs(root: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return vec![];
+    };
+
+    let mut dirs: Vec<_> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .filter(|n| {
+            n.strip_prefix("day-")
+                .is_some_and(|d| d.len() == 2 && d.bytes().all(|b| b.is_ascii_digit()))
+        })
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn members(root: &Path) -> Result<Vec<String>, String> {
+    let manifest = fs::read_to_string(root.join("Cargo.toml"))
+        .map_err(|err| format!("failed to read Cargo.toml: {err}"))?;
+    let doc: DocumentMut = manifest.parse().map_err(|err| format!("{err}"))?;
+
+    let members = doc["workspace"]["members"]
+        .as_array()
+        .ok_or("workspace has no members")?;
+    Ok(members
+        .iter()
+        .filter_map(|m| m.as_str().map(String::from))
+        .collect())
+}
+
+fn sources(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut res = vec![];
+    for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+        if path.is_dir() {
+            res.extend(sources(&path));
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            res.push(path);
+        }
+    }
+    res.sort();
+    res
+}
+
+/// Lines of the sources of the given crate matching the predicate, as
+/// `path:line` relative to the workspace root.
+fn grep(root: &Path, krate: &str, matches: impl Fn(&str) -> bool) -> Vec<String> {
+    sources(&root.join(krate).join("src"))
+        .iter()
+        .flat_map(|path| {
+            let source = fs::read_to_string(path).unwrap_or_default();
+            let path = path.strip_prefix(root).unwrap_or(path).to_owned();
+            source
+                .lines()
+                .enumerate()
+                .filter(|(_, l)| matches(l))
+                .map(|(i, _)| format!("{}:{}", path.display(), i + 1))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn check_day(root: &Path, name: &str, members: &[String], registry: &Registry) -> Vec<Problem> {
+    let dir = root.join(name);
+    let mut problems = vec![];
+    let mut problem = |check, detail: String| {
+        problems.push(Problem {
+            krate: name.into(),
+            check,
+            detail,
+        })
+    };
+
+    if !members.iter().any(|m| m == name) {
+        problem("member", "not a member of the workspace".into());
+    }
+    let number = name[4..].parse().unwrap_or(0);
+    if registry.get(number).is_none() {
+        problem("registry", "not registered in days/src/lib.rs".into());
+    }
+
+    if !dir.join("input.txt").exists() {
+        problem("input", "input.txt is missing".into());
+    }
+    match fs::metadata(dir.join("challenge.txt")) {
+        Ok(meta) if meta.len() > 0 => {}
+        Ok(_) => problem("challenge", "challenge.txt is empty".into()),
+        Err(_) => problem("challenge", "challenge.txt is missing".into()),
+    }
+    if dir.join("test_input.txt").exists() {
+        problem("test input", "test_input.txt is left over".into());
+    }
+    for line in grep(root, name, |l| l.contains("TODO:")) {
+        problem("todo", format!("TODO marker at {line}"));
+    }
+
+    let answers = Answers::load(&dir);
+    let missing: Vec<_> = Part::ALL
+        .into_iter()
+        .filter(|&p| answers.get("input.txt").and_then(|a| a.part(p)).is_none())
+        .map(|p| p.to_string())
+        .collect();
+    if !missing.is_empty() {
+        problem(
+            "answers",
+            format!(
+                "no answer of part {} recorded for input.txt",
+                missing.join(" and ")
+            ),
+        );
+    }
+
+    problems
+}
+
+fn pins_nightly(root: &Path) -> bool {
+    TOOLCHAIN_FILES.iter().any(|f| {
+        fs::read_to_string(root.join(f)).is_ok_and(|toolchain| toolchain.contains("nightly"))
+    })
+}
+
+/// Workspace members the given member depends on by path.
+fn path_dependencies(root: &Path, member: &str, members: &[String]) -> Vec<String> {
+    let Ok(manifest) = fs::read_to_string(root.join(member).join("Cargo.toml")) else {
+        return vec![];
+    };
+    let Ok(doc) = manifest.parse::<DocumentMut>() else {
+        return vec![];
+    };
+
+    ["dependencies", "dev-dependencies", "build-dependencies"]
+        .iter()
+        .filter_map(|t| doc.get(t).and_then(|t| t.as_table_like()))
+        .flat_map(|t| t.iter())
+        .filter_map(|(_, dep)| dep.as_table_like()?.get("path")?.as_str().map(String::from))
+        .filter_map(|path| Some(Path::new(&path).file_name()?.to_string_lossy().into_owned()))
+        .filter(|dep| members.contains(dep))
+        .collect()
+}
+
+/// Crates using unstable features, and crates depending on those, need a
+/// nightly toolchain, which is reported unless the workspace pins one.
+fn check_nightly(root: &Path, members: &[String]) -> Vec<Problem> {
+    let features: BTreeMap<_, _> = members
+        .iter()
+        .map(|m| {
+            (
+                m,
+                grep(root, m, |l| l.trim_start().starts_with("#![feature(")),
+            )
+        })
+        .filter(|(_, lines)| !lines.is_empty())
+        .collect();
+    if features.is_empty() || pins_nightly(root) {
+        return vec![];
+    }
+
+    let dependencies: BTreeMap<_, _> = members
+        .iter()
+        .map(|m| (m, path_dependencies(root, m, members)))
+        .collect();
+
+    let mut problems = vec![];
+    for member in members {
+        if let Some(lines) = features.get(member) {
+            problems.push(Problem {
+                krate: member.clone(),
+                check: "nightly",
+                detail: format!(
+                    "unstable feature at {} needs a nightly toolchain, which is not pinned",
+                    lines.join(", ")
+                ),
+            });
+        }
+
+        let mut queue = dependencies[member].clone();
+        let mut seen = vec![];
+        while let Some(dep) = queue.pop() {
+            if !seen.contains(&dep) {
+                queue.extend(dependencies.get(&dep).into_iter().flatten().cloned());
+                seen.push(dep);
+            }
+        }
+        let mut nightly: Vec<_> = seen
+            .into_iter()
+            .filter(|d| features.contains_key(d))
+            .collect();
+        nightly.sort();
+        if !nightly.is_empty() {
+            problems.push(Problem {
+                krate: member.clone(),
+                check: "nightly",
+                detail: format!(
+                    "depends on {}, which need a nightly toolchain",
+                    nightly.join(", ")
+                ),
+            });
+        }
+    }
+
+    problems
+}
+
+/// Checks the consistency of the whole workspace: every day directory is
+/// a registered workspace member with its input, puzzle text and answers,
+/// without leftovers of solving it, and the workspace pins a nightly
+/// toolchain if any crate needs one. Returns whether no problems were
+/// found.
+pub fn doctor(args: &Args, root: &Path, registry: &Registry) -> bool {
+    let members = match members(root) {
+        Ok(members) => members,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+
+    let days = day_dirs(root);
+    let mut problems = vec![];
+
+    for day in &days {
+        problems.extend(check_day(root, day, &members, registry));
+    }
+    for member in &members {
+        if !root.join(member).is_dir() {
+            problems.push(Problem {
+                krate: member.clone(),
+                check: "member",
+                detail: "workspace member has no directory".into(),
+            });
+        }
+    }
+    problems.extend(check_nightly(root, &members));
+
+    if problems.is_empty() {
+        println!(
+            "No problems found in {} days and {} workspace members.",
+            days.len(),
+            members.len()
+        );
+        return true;
+    }
+
+    let mut table = Table::new(&["CRATE", "CHECK", "PROBLEM"]);
+    for p in problems {
+        table.row(vec![p.krate, p.check.into(), p.detail]);
+    }
+    table.print(args.color);
+    false
+}
@@ -0,0 +1,58 @@
+use crate::{args::Args, table::Table};
+use lib::{Answers, Day, Part};
+use std::fs;
+
+/// Extracts the worked examples from the `challenge.txt` of the given days
+/// and writes them as example fixtures, each tested by its own test case.
+/// Returns whether all days could be processed.
+pub fn examples(args: &Args, days: &[&Day]) -> bool {
+    let mut table = Table::new(&["DAY", "EXAMPLE", "PART 1", "PART 2"]);
+    let mut ok = true;
+
+    for day in days {
+        let dir = day.dir();
+        let challenge = match fs::read_to_string(dir.join("challenge.txt")) {
+            Ok(challenge) => challenge,
+            Err(err) => {
+                eprintln!("{}: failed to read challenge.txt: {err}", day.name);
+                ok = false;
+                continue;
+            }
+        };
+
+        let examples = lib::extract_examples(&challenge);
+        if examples.is_empty() {
+            eprintln!("{}: no examples found in challenge.txt", day.name);
+            continue;
+        }
+
+        let files = match lib::write_examples(&dir, &examples) {
+            Ok(files) => files,
+            Err(err) => {
+                eprintln!("{}: failed to write examples: {err}", day.name);
+                ok = false;
+                continue;
+            }
+        };
+
+        if let Err(err) = lib::write_example_tests(&dir) {
+            eprintln!("{}: failed to add the example tests: {err}", day.name);
+            ok = false;
+        }
+
+        let answers = Answers::load(&dir);
+        for file in files {
+            let answer = answers.get(&file);
+            let part = |p| answer.and_then(|a| a.part(p)).unwrap_or("-").to_owned();
+            table.row(vec![
+                day.name.into(),
+                file.clone(),
+                part(Part::One),
+                part(Part::Two),
+            ]);
+        }
+    }
+
+    table.print(args.color);
+    ok
+}
@@ -0,0 +1,334 @@
+use core::fmt;
+use std::{
+    collections::HashMap,
+    env,
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// Minimum time between two requests to the puzzle server, also across
+/// separate invocations of the runner.
+pub const THROTTLE: Duration = Duration::from_secs(5);
+
+pub const USER_AGENT: &str = "github.com/zekroTJA/aoc2023";
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    Status(u16, String),
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(f, "SESSION_TOKEN is not set"),
+            FetchError::Status(code, body) => {
+                write!(f, "request failed with status {code}: {body}")
+            }
+            FetchError::Transport(err) => write!(f, "request failed: {err}"),
+            FetchError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(err: io::Error) -> Self {
+        FetchError::Io(err)
+    }
+}
+
+/// Settings of the puzzle server, read from the environment and the `.env`
+/// file at the workspace root. Environment variables take precedence.
+///
+/// ```text
+/// SESSION_TOKEN=53616c7465645f5f...
+/// AOC_BASE_URL=https://adventofcode.com
+/// AOC_YEAR=2023
+/// AOC_THROTTLE_SECS=5
+/// ```
+#[derive(Debug)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub year: u16,
+    pub throttle: Duration,
+}
+
+impl Config {
+    pub fn load(root: &Path) -> Self {
+        let dotenv = fs::read_to_string(root.join(".env"))
+            .map(|raw| parse_dotenv(&raw))
+            .unwrap_or_default();
+        let var = |key: &str| env::var(key).ok().or_else(|| dotenv.get(key).cloned());
+
+        Self {
+            session: var("SESSION_TOKEN").filter(|s| !s.is_empty()),
+            base_url: var("AOC_BASE_URL").unwrap_or_else(|| DEFAULT_BASE_URL.into()),
+            year: var("AOC_YEAR")
+                .and_then(|y| y.parse().ok())
+                .unwrap_or(DEFAULT_YEAR),
+            throttle: var("AOC_THROTTLE_SECS")
+                .and_then(|t| t.parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(THROTTLE),
+        }
+    }
+}
+
+fn parse_dotenv(raw: &str) -> HashMap<String, String> {
+    raw.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|l| l.trim_start_matches("export ").split_once('='))
+        .map(|(k, v)| (k.trim().into(), v.trim().trim_matches(['"', '\'']).into()))
+        .collect()
+}
+
+/// Downloads puzzle inputs into a local cache. Inputs which are already
+/// cached are never downloaded again.
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+    throttle: Duration,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.into(),
+            cache_dir: cache_dir.into(),
+            throttle: THROTTLE,
+        }
+    }
+
+    pub fn with_throttle(mut self, throttle: Duration) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    pub fn cache_file(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day-{day:02}.txt"))
+    }
+
+    /// Returns the input of the given day, downloading it only if it is not
+    /// cached yet.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let file = self.cache_file(year, day);
+        if file.exists() {
+            return Ok(fs::read_to_string(file)?);
+        }
+
+        throttle(&self.cache_dir, self.throttle)?;
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let res = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+
+        let input = match res {
+            Ok(res) => res.into_string()?,
+            Err(ureq::Error::Status(code, res)) => {
+                let body = res.into_string().unwrap_or_default();
+                return Err(FetchError::Status(code, body.trim().into()));
+            }
+            Err(err) => return Err(FetchError::Transport(err.to_string())),
+        };
+
+        fs::create_dir_all(file.parent().unwrap())?;
+        fs::write(file, &input)?;
+
+        Ok(input)
+    }
+}
+
+/// Blocks until the throttle interval since the last request recorded in
+/// the given directory has passed and records the current request.
+pub fn throttle(dir: &Path, interval: Duration) -> io::Result<()> {
+    let stamp = dir.join(".last_request");
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+
+    let last = fs::read_to_string(&stamp)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .map(Duration::from_millis);
+
+    if let Some(elapsed) = last.and_then(|l| now.checked_sub(l)) {
+        if let Some(remaining) = interval.checked_sub(elapsed) {
+            thread::sleep(remaining);
+        }
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    fs::create_dir_all(dir)?;
+    fs::write(stamp, now.as_millis().to_string())
+}
+
+/// Fetches the inputs of the given days and places them as `input.txt`
+/// into the day directories which exist already. Returns whether all
+/// inputs could be fetched.
+pub fn fetch(root: &Path, days: &[u8]) -> bool {
+    let config = Config::load(root);
+    let Some(session) = &config.session else {
+        eprintln!("{}", FetchError::MissingSession);
+        return false;
+    };
+
+    let fetcher = Fetcher::new(
+        &config.base_url,
+        session,
+        root.join(".cache").join("inputs"),
+    )
+    .with_throttle(config.throttle);
+    let mut ok = true;
+
+    for &day in days {
+        let name = format!("day-{day:02}");
+        let dir = root.join(&name);
+        let file = dir.join("input.txt");
+        if file.exists() {
+            println!("{name}: input.txt already exists");
+            continue;
+        }
+
+        let input = match fetcher.input(config.year, day) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{name}: {err}");
+                ok = false;
+                continue;
+            }
+        };
+
+        if !dir.is_dir() {
+            println!(
+                "{name}: cached at {}",
+                fetcher.cache_file(config.year, day).display()
+            );
+        } else if let Err(err) = fs::write(&file, input) {
+            eprintln!("{name}: failed to write input.txt: {err}");
+            ok = false;
+        } else {
+            println!("{name}: fetched input.txt");
+        }
+    }
+
+    ok
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+    };
+
+    /// Serves the given response to a single request and returns the
+    /// address of the server and a handle yielding the received request.
+    pub(crate) fn stub(response: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+            let body_len = |request: &[u8]| {
+                let request = String::from_utf8_lossy(request);
+                let (head, body) = request.split_once("\r\n\r\n")?;
+                let len = head
+                    .lines()
+                    .find_map(|l| l.strip_prefix("Content-Length: "))
+                    .map_or(0, |l| l.parse().unwrap());
+                Some((len, body.len()))
+            };
+            while body_len(&request).is_none_or(|(len, read)| read < len) {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        (addr, handle)
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_cached() {
+        let (addr, handle) = stub("HTTP/1.1 200 OK\r\nContent-Length: 6\r\n\r\n1 2 3\n");
+        let dir = cache_dir("cached");
+        let fetcher = Fetcher::new(&addr, "abc", &dir).with_throttle(Duration::ZERO);
+
+        assert_eq!(fetcher.input(2023, 5).unwrap(), "1 2 3\n");
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/5/input "));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+
+        // The stub server is gone, so this can only succeed from the cache.
+        assert_eq!(fetcher.input(2023, 5).unwrap(), "1 2 3\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_status() {
+        let (addr, handle) = stub("HTTP/1.1 404 Not Found\r\nContent-Length: 9\r\n\r\nNot Found");
+        let dir = cache_dir("status");
+        let fetcher = Fetcher::new(&addr, "abc", &dir).with_throttle(Duration::ZERO);
+
+        let err = fetcher.input(2023, 26).unwrap_err();
+        assert!(matches!(err, FetchError::Status(404, _)));
+        assert!(!fetcher.cache_file(2023, 26).exists());
+
+        handle.join().unwrap();
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_existing() {
+        let root = cache_dir("existing");
+        fs::create_dir_all(root.join("day-05")).unwrap();
+        fs::write(root.join("day-05").join("input.txt"), "1 2 3\n").unwrap();
+        fs::write(
+            root.join(".env"),
+            "SESSION_TOKEN=abc\nAOC_BASE_URL=http://127.0.0.1:1\nAOC_THROTTLE_SECS=0\n",
+        )
+        .unwrap();
+
+        // Nothing listens on the server, so this can only succeed without a
+        // request, although the cache is empty.
+        assert!(fetch(&root, &[5]));
+        assert!(!root.join(".cache").exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_parse_dotenv() {
+        let env = parse_dotenv("# comment\nSESSION_TOKEN=\"abc\"\nexport AOC_YEAR=2022\n");
+        assert_eq!(env.get("SESSION_TOKEN").unwrap(), "abc");
+        assert_eq!(env.get("AOC_YEAR").unwrap(), "2022");
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{
+    args::Args,
+    color,
+    panic::{self, Panic},
+};
+use lib::{Day, Input, Rng};
+use std::collections::BTreeMap;
+
+/// Characters likely to confuse a parser, inserted next to the ones taken
+/// from the input itself.
+const SPECIAL: &[u8] = b"0123456789 \n-+,.:;=#?{}()[]<>";
+
+/// Numbers likely to overflow a parser, replacing the ones of the input.
+const NUMBERS: &[&str] = &["0", "1", "-1", "255", "4294967296", "99999999999999999999"];
+
+const MAX_MUTATIONS: usize = 4;
+
+/// Start and end of the runs of digits of the input.
+fn numbers(bytes: &[u8]) -> Vec<(usize, usize)> {
+    let mut res = vec![];
+    let mut start = None;
+    for (i, b) in bytes.iter().chain([&b' ']).enumerate() {
+        match (b.is_ascii_digit(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                res.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    res
+}
+
+fn mutate_bytes(input: &str, rng: &mut Rng) -> String {
+    let mut bytes = input.as_bytes().to_vec();
+    let byte = |rng: &mut Rng, bytes: &[u8]| match bytes.is_empty() || rng.chance(0.5) {
+        true => *rng.pick(SPECIAL),
+        false => *rng.pick(bytes),
+    };
+
+    match rng.range(0..4) {
+        _ if bytes.is_empty() => bytes.push(byte(rng, &bytes)),
+        0 => {
+            bytes.remove(rng.range(0..bytes.len()));
+        }
+        1 => {
+            let b = byte(rng, &bytes);
+            bytes.insert(rng.range(0..bytes.len() + 1), b);
+        }
+        2 => {
+            let i = rng.range(0..bytes.len());
+            bytes[i] = byte(rng, &bytes);
+        }
+        _ => match numbers(&bytes).as_slice() {
+            [] => bytes.truncate(rng.range(0..bytes.len())),
+            numbers => {
+                let &(start, end) = rng.pick(numbers);
+                bytes.splice(start..end, rng.pick(NUMBERS).bytes());
+            }
+        },
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn mutate_lines(input: &str, rng: &mut Rng) -> String {
+    let mut lines: Vec<_> = input.split('\n').map(str::to_owned).collect();
+    let i = rng.range(0..lines.len());
+
+    match rng.range(0..5) {
+        0 => {
+            lines.remove(i);
+        }
+        1 => lines.insert(i, lines[i].clone()),
+        2 => {
+            let j = rng.range(0..lines.len());
+            lines.swap(i, j);
+        }
+        3 => {
+            let len = rng.range(0..lines[i].len() + 1);
+            lines[i] = lines[i].chars().take(len).collect();
+        }
+        _ => lines.insert(i, String::new()),
+    }
+
+    lines.join("\n")
+}
+
+/// Applies a few random byte or line level mutations to the input, which
+/// is normalized like [`Input::read`] does.
+fn mutate(input: &str, rng: &mut Rng) -> String {
+    let mut input = input.to_owned();
+    for _ in 0..rng.range(1..MAX_MUTATIONS + 1) {
+        input = match rng.chance(0.5) {
+            true => mutate_bytes(&input, rng),
+            false => mutate_lines(&input, rng),
+        };
+    }
+    input.trim_end().to_owned()
+}
+
+/// Removes chunks of halving size from the units as long as the input
+/// joined from the remaining ones still fails.
+fn reduce<T: Clone>(
+    mut units: Vec<T>,
+    join: impl Fn(&[T]) -> String,
+    fails: &impl Fn(&str) -> bool,
+) -> Vec<T> {
+    let mut chunk = units.len().div_ceil(2);
+    while chunk > 0 {
+        let mut i = 0;
+        while i < units.len() {
+            let mut candidate = units.clone();
+            candidate.drain(i..(i + chunk).min(units.len()));
+            match fails(join(&candidate).trim_end()) {
+                true => units = candidate,
+                false => i += chunk,
+            }
+        }
+        chunk /= 2;
+    }
+    units
+}
+
+/// Shrinks the failing input, first by whole lines and then by single
+/// characters, to one which still fails.
+fn minimize(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let lines = reduce(
+        input.split('\n').collect(),
+        |lines| lines.join("\n"),
+        &fails,
+    );
+    let chars = reduce(
+        lines.join("\n").chars().collect(),
+        |chars| chars.iter().collect(),
+        &fails,
+    );
+    chars.iter().collect::<String>().trim_end().to_owned()
+}
+
+fn parse(day: &Day, input: &str) -> Result<(), Panic> {
+    panic::catch(|| {
+        day.parse(input);
+    })
+}
+
+fn print_input(input: &str) {
+    if input.is_empty() {
+        println!("    (empty input)");
+    }
+    for line in input.split('\n').filter(|_| !input.is_empty()) {
+        println!("    | {line}");
+    }
+}
+
+/// Feeds the parsers of the given days with mutations of their example
+/// and test inputs and reports every distinct panic, by its location,
+/// along with the smallest input causing it. Days keeping the raw input
+/// have no parser of their own and are skipped. Parsers run in this
+/// process, so one which never returns stalls the fuzzer. Returns whether
+/// no panic was found.
+pub fn fuzz(args: &Args, days: &[&Day]) -> bool {
+    let mut ok = true;
+
+    for day in days {
+        if day.raw_input() {
+            let msg = format!("{}: parser not separated from the parts, skipped", day.name);
+            println!("{}", color::paint(&msg, color::YELLOW, args.color));
+            continue;
+        }
+
+        let seeds: Vec<_> = Input::examples(day)
+            .into_iter()
+            .chain([Input::day_file(day, "test_input.txt")])
+            .filter_map(|i| i.read().ok())
+            .filter(|i| !i.is_empty())
+            .collect();
+        if seeds.is_empty() {
+            eprintln!("{}: no example inputs to mutate", day.name);
+            continue;
+        }
+
+        let mut rng = Rng::new(args.seed);
+        let mut panics: BTreeMap<String, (Panic, String)> = BTreeMap::new();
+
+        for _ in 0..args.cases {
+            let input = mutate(rng.pick(&seeds), &mut rng);
+            let Err(panic) = parse(day, &input) else {
+                continue;
+            };
+            if panics.contains_key(&panic.location) {
+                continue;
+            }
+
+            let input = minimize(&input, |i| {
+                parse(day, i).is_err_and(|p| p.location == panic.location)
+            });
+            let panic = parse(day, &input).unwrap_err();
+            panics.insert(panic.location.clone(), (panic, input));
+        }
+
+        if panics.is_empty() {
+            let msg = format!("{}: {} inputs, no panics", day.name, args.cases);
+            println!("{}", color::paint(&msg, color::GREEN, args.color));
+            continue;
+        }
+
+        ok = false;
+        let msg = format!(
+            "{}: {} inputs, {} distinct panics",
+            day.name,
+            args.cases,
+            panics.len()
+        );
+        println!("{}", color::paint(&msg, color::RED, args.color));
+        for (panic, input) in panics.values() {
+            println!("  {}", panic.describe(Some(input)).replace('\n', "\n  "));
+            print_input(input);
+        }
+    }
+
+    ok
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_minimize() {
+        let fails = |i: &str| i.lines().any(|l| l.contains("b2"));
+        assert_eq!(minimize("a1 a2\nb1 b2 b3\nc1", fails), "b2");
+
+        assert_eq!(numbers(b"a12 3\n45"), vec![(1, 3), (4, 5), (6, 8)]);
+
+        let mut rng = Rng::new(0);
+        for _ in 0..1000 {
+            let input = mutate("Time: 7 15\nDistance: 9 40", &mut rng);
+            assert_eq!(input, input.trim_end());
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{args::Args, bench::Measurement, color, table::Table};
+use lib::Day;
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const HEADER: &str = "commit\ttimestamp\tday\tphase\tmedian_ns\tmean_ns";
+
+/// Regressions are flagged once the median time grows by more than this
+/// many percent compared to the previous run.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// A benchmark result of a single phase of a day, as stored in the
+/// history file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub commit: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub day: String,
+    pub phase: String,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Record {
+    fn parse(line: &str) -> Option<Self> {
+        let mut split = line.split('\t');
+        let mut next = || split.next();
+
+        Some(Self {
+            commit: next()?.into(),
+            timestamp: next()?.parse().ok()?,
+            day: next()?.into(),
+            phase: next()?.into(),
+            median: Duration::from_nanos(next()?.parse().ok()?),
+            mean: Duration::from_nanos(next()?.parse().ok()?),
+        })
+    }
+
+    fn to_tsv(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.commit,
+            self.timestamp,
+            self.day,
+            self.phase,
+            self.median.as_nanos(),
+            self.mean.as_nanos()
+        )
+    }
+}
+
+/// The history file, which is local to the machine the benchmarks ran on.
+pub fn file(root: &Path) -> PathBuf {
+    root.join("bench_history.tsv")
+}
+
+pub fn load(path: &Path) -> Vec<Record> {
+    fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(Record::parse)
+        .collect()
+}
+
+/// The short hash of the checked out commit, marked as dirty if there are
+/// uncommitted changes.
+fn commit(root: &Path) -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .output()
+            .ok()
+    };
+
+    let Some(hash) = git(&["rev-parse", "--short", "HEAD"]).filter(|o| o.status.success()) else {
+        return "unknown".into();
+    };
+    let hash = String::from_utf8_lossy(&hash.stdout).trim().to_owned();
+
+    match git(&["diff", "--quiet", "HEAD"]).map(|o| o.status.success()) {
+        Some(false) => format!("{hash}-dirty"),
+        _ => hash,
+    }
+}
+
+/// Appends the given measurements to the history file.
+pub fn save(root: &Path, measurements: &[Measurement]) -> io::Result<()> {
+    let path = file(root);
+    let commit = commit(root);
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let mut out = String::new();
+    if !path.exists() {
+        out += HEADER;
+        out += "\n";
+    }
+    for m in measurements {
+        let record = Record {
+            commit: commit.clone(),
+            timestamp,
+            day: m.day.into(),
+            phase: m.phase.into(),
+            median: m.stats.median,
+            mean: m.stats.mean,
+        };
+        out += &record.to_tsv();
+        out += "\n";
+    }
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(out.as_bytes())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Change {
+    Regression,
+    Improvement,
+    Unchanged,
+}
+
+/// Relative change of the median from `prev` to `cur` in percent.
+fn change(prev: Duration, cur: Duration) -> f64 {
+    (cur.as_secs_f64() / prev.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0) * 100.0
+}
+
+fn classify(change: f64, threshold: f64) -> Change {
+    if change > threshold {
+        Change::Regression
+    } else if change < -threshold {
+        Change::Improvement
+    } else {
+        Change::Unchanged
+    }
+}
+
+/// Formats seconds since the Unix epoch as UTC date and time.
+pub fn format_timestamp(secs: u64) -> String {
+    let (days, rem) = ((secs / 86400) as i64, secs % 86400);
+
+    // Civil date from days since the epoch, see
+    // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        rem / 3600,
+        rem % 3600 / 60
+    )
+}
+
+/// Prints the recorded timings of each phase of the given days over time,
+/// flagging changes of the median by more than the threshold. Returns
+/// whether the latest run of every phase is free of regressions.
+pub fn history(args: &Args, root: &Path, days: &[&Day]) -> bool {
+    let records = load(&file(root));
+    let mut table = Table::new(&[
+        "DAY", "PHASE", "COMMIT", "DATE", "MEDIAN", "MEAN", "CHANGE", "STATUS",
+    ]);
+    let mut ok = true;
+
+    for day in days {
+        for phase in ["parse", "part1", "part2"] {
+            let runs: Vec<_> = records
+                .iter()
+                .filter(|r| r.day == day.name && r.phase == phase)
+                .collect();
+
+            for (i, run) in runs.iter().enumerate() {
+                let change = i
+                    .checked_sub(1)
+                    .map(|prev| change(runs[prev].median, run.median));
+                let status = change.map_or(Change::Unchanged, |c| classify(c, args.threshold));
+
+                if i == runs.len() - 1 && status == Change::Regression {
+                    ok = false;
+                }
+
+                table.row(vec![
+                    day.name.into(),
+                    phase.into(),
+                    run.commit.clone(),
+                    format_timestamp(run.timestamp),
+                    format!("{:.2?}", run.median),
+                    format!("{:.2?}", run.mean),
+                    change.map_or("-".into(), |c| format!("{c:+.1}%")),
+                    match status {
+                        Change::Regression => "REGRESSION".into(),
+                        Change::Improvement => "IMPROVED".into(),
+                        Change::Unchanged => String::new(),
+                    },
+                ]);
+                match status {
+                    Change::Regression => table.color_last(color::RED),
+                    Change::Improvement => table.color_last(color::GREEN),
+                    Change::Unchanged => {}
+                }
+            }
+        }
+    }
+
+    table.print(args.color);
+    ok
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let line = "1a2b3c4-dirty\t1703030400\tday-06\tpart2\t27300000\t27900000";
+        let record = Record::parse(line).unwrap();
+
+        assert_eq!(record.median, Duration::from_micros(27300));
+        assert_eq!(record.to_tsv(), line);
+        assert_eq!(Record::parse(HEADER), None);
+        assert_eq!(format_timestamp(record.timestamp), "2023-12-20 00:00");
+    }
+
+    #[test]
+    fn test_classify() {
+        let ms = Duration::from_millis;
+        assert_eq!(classify(change(ms(10), ms(12)), 10.0), Change::Regression);
+        assert_eq!(classify(change(ms(10), ms(8)), 10.0), Change::Improvement);
+        assert_eq!(
+            classify(change(ms(10), ms(105) / 10), 10.0),
+            Change::Unchanged
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{args::Args, history::format_timestamp, panic};
+use lib::{Answers, Day, Input, Part, PartResult, Render};
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+const STYLE: &str = "\
+body { font-family: sans-serif; max-width: 60rem; margin: 2rem auto; padding: 0 1rem; \
+background: #0f0f23; color: #cccccc; }
+a { color: #009900; }
+h1, h2 { color: #00cc00; font-weight: normal; }
+h2 { margin-top: 3rem; border-bottom: 1px solid #333340; }
+table { border-collapse: collapse; }
+th, td { text-align: left; padding: 0.2rem 1.5rem 0.2rem 0; }
+td.answer { font-family: monospace; color: #ffffff; }
+.ok { color: #00cc00; }
+.error { color: #ff4444; }
+pre { font-size: 0.5rem; line-height: 1; overflow-x: auto; background: #10101a; \
+border: 1px solid #333340; padding: 0.5rem; }
+pre.error { font-size: 0.9rem; line-height: 1.3; }
+summary { cursor: pointer; }";
+
+/// Everything shown for a single day.
+struct Section {
+    day: &'static str,
+    title: String,
+    parse: Option<Duration>,
+    results: Vec<PartResult>,
+    expected: [Option<String>; 2],
+    renders: Vec<Render>,
+    error: Option<String>,
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn section(day: &Day) -> Section {
+    let dir = day.dir();
+    let title = fs::read_to_string(dir.join("challenge.txt"))
+        .ok()
+        .and_then(|c| lib::challenge_title(&c).map(String::from))
+        .unwrap_or_else(|| day.name.into());
+    let answers = Answers::load(&dir);
+    let expected = Part::ALL.map(|p| {
+        answers
+            .get("input.txt")
+            .and_then(|a| a.part(p))
+            .map(String::from)
+    });
+
+    let mut section = Section {
+        day: day.name,
+        title,
+        parse: None,
+        results: vec![],
+        expected,
+        renders: vec![],
+        error: None,
+    };
+
+    let input = Input::day_file(day, "input.txt");
+    let raw = match input.read() {
+        Ok(raw) => raw,
+        Err(err) => {
+            section.error = Some(err.to_string());
+            return section;
+        }
+    };
+
+    let res = panic::catch(|| {
+        let start = Instant::now();
+        let parsed = day.parse(&raw);
+        section.parse = Some(start.elapsed());
+
+        for part in Part::ALL {
+            section.results.push(day.run(input.name(), &parsed, part));
+        }
+        section.renders = day.renders(&parsed);
+    });
+    if let Err(panic) = res {
+        section.error = Some(panic.describe(Some(&raw)));
+    }
+
+    section
+}
+
+fn write_section(out: &mut String, section: &Section) {
+    *out += &format!(
+        "<h2 id=\"{}\">{}</h2>\n<table>\n<tr><th></th><th>Answer</th><th>Time</th><th></th></tr>\n",
+        section.day,
+        escape(&section.title)
+    );
+
+    if let Some(parse) = section.parse {
+        *out += &format!("<tr><td>Parse</td><td></td><td>{parse:.2?}</td><td></td></tr>\n");
+    }
+    for res in &section.results {
+        let check = match &section.expected[res.part.number() as usize - 1] {
+            Some(e) if *e == res.answer => "<span class=\"ok\">&#10003; recorded</span>".into(),
+            Some(e) => format!(
+                "<span class=\"error\">&#10007; recorded {}</span>",
+                escape(e)
+            ),
+            None => String::new(),
+        };
+        *out += &format!(
+            "<tr><td>Part {}</td><td class=\"answer\">{}</td><td>{:.2?}</td><td>{check}</td></tr>\n",
+            res.part,
+            escape(&res.answer),
+            res.time
+        );
+    }
+    *out += "</table>\n";
+
+    if let Some(err) = &section.error {
+        *out += &format!("<pre class=\"error\">{}</pre>\n", escape(err));
+    }
+    for render in &section.renders {
+        *out += &format!(
+            "<details>\n<summary>{}</summary>\n<pre>{}</pre>\n</details>\n",
+            escape(&render.name),
+            escape(&render.text)
+        );
+    }
+}
+
+/// Runs the given days with their real input and writes a self-contained
+/// HTML page with the title, answers, timings and renders of each day.
+/// Returns whether every day could be run.
+pub fn report(args: &Args, root: &Path, days: &[&Day]) -> bool {
+    let sections: Vec<_> = days.iter().map(|d| section(d)).collect();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code 2023</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n\
+         <h1>Advent of Code 2023</h1>\n<p>Generated on {} UTC.</p>\n<ul>\n",
+        format_timestamp(now)
+    );
+    for section in &sections {
+        out += &format!(
+            "<li><a href=\"#{}\">{}</a></li>\n",
+            section.day,
+            escape(&section.title)
+        );
+    }
+    out += "</ul>\n";
+    for section in &sections {
+        write_section(&mut out, section);
+    }
+    out += "</body>\n</html>\n";
+
+    let path = args
+        .output
+        .clone()
+        .unwrap_or_else(|| root.join("report.html"));
+    if let Err(err) = fs::write(&path, out) {
+        eprintln!("failed to write {}: {err}", path.display());
+        return false;
+    }
+    println!("wrote {}", path.display());
+
+    let mut ok = true;
+    for section in sections.iter().filter(|s| s.error.is_some()) {
+        eprintln!("{}: {}", section.day, section.error.as_ref().unwrap());
+        ok = false;
+    }
+    ok
+}
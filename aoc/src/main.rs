@@ -0,0 +1,182 @@
+mod args;
+mod bench;
+mod check;
+mod color;
+mod doctor;
+mod examples;
+mod fetch;
+mod fuzz;
+mod history;
+mod html;
+mod new;
+mod panic;
+mod run;
+mod sandbox;
+mod scale;
+mod submit;
+mod summary;
+mod table;
+mod variants;
+mod watch;
+
+use args::{Args, Command, Days, USAGE};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+/// The root directory of the workspace, which is overridden by
+/// [`lib::ROOT_ENV`] if set.
+fn root() -> PathBuf {
+    match env::var_os(lib::ROOT_ENV) {
+        Some(root) => root.into(),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .into(),
+    }
+}
+
+#[global_allocator]
+static ALLOC: lib::Counting = lib::Counting;
+
+fn main() {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}\n\n{USAGE}");
+            exit(1);
+        }
+    };
+
+    if let (true, Some(memory)) = (args.worker, args.memory) {
+        lib::set_memory_limit(memory);
+    }
+
+    panic::install(args.worker);
+
+    if let Some(spec) = &args.trace {
+        if let Err(err) = lib::set_trace(spec) {
+            eprintln!("{err}\n\n{USAGE}");
+            exit(1);
+        }
+        if !lib::TRACING && !args.worker {
+            eprintln!("tracing is compiled out of this build, rebuild with `--features trace`");
+        }
+    }
+
+    if args.help {
+        println!("{USAGE}");
+        return;
+    }
+
+    let registry = days::registry();
+
+    match args.command {
+        Command::Fetch => {
+            if !fetch::fetch(&root(), &args.days.numbers(&registry)) {
+                exit(1);
+            }
+            return;
+        }
+        Command::Doctor => {
+            if !doctor::doctor(&args, &root(), &registry) {
+                exit(1);
+            }
+            return;
+        }
+        Command::New => {
+            let days = match args.days {
+                Days::Latest => vec![registry.last().map_or(1, |d| d.number + 1)],
+                _ => args.days.numbers(&registry),
+            };
+            if let Err(err) = days.iter().try_for_each(|&d| new::check_day(d)) {
+                eprintln!("{err}");
+                exit(1);
+            }
+            for day in days {
+                if let Err(err) = new::new(&root(), day) {
+                    eprintln!("{err}");
+                    exit(1);
+                }
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    let days = args.days.select(&registry);
+
+    if days.is_empty() {
+        eprintln!("No matching days found!");
+        exit(1);
+    }
+
+    if args.input.is_some() && days.len() > 1 {
+        eprintln!("--input and --stdin can only be used with a single day!");
+        exit(1);
+    }
+
+    match args.command {
+        Command::Run => run::run(&args, &days),
+        Command::Summary => {
+            if !summary::summary(&args, &days) {
+                exit(1);
+            }
+        }
+        Command::Bench => bench::bench(&args, &days),
+        Command::History => {
+            if !history::history(&args, &root(), &days) {
+                exit(1);
+            }
+        }
+        Command::Scale => {
+            if !scale::scale(&args, &days) {
+                exit(1);
+            }
+        }
+        Command::Check => {
+            if !check::check(&args, &days) {
+                exit(1);
+            }
+        }
+        Command::Variants => {
+            if !variants::variants(&args, &days) {
+                exit(1);
+            }
+        }
+        Command::Fuzz => {
+            if !fuzz::fuzz(&args, &days) {
+                exit(1);
+            }
+        }
+        Command::Report => {
+            if !html::report(&args, &root(), &days) {
+                exit(1);
+            }
+        }
+        Command::Submit => {
+            if days.len() > 1 {
+                eprintln!("Only a single day can be submitted at once!");
+                exit(1);
+            }
+            if !submit::submit(&args, &root(), days[0]) {
+                exit(1);
+            }
+        }
+        Command::Watch => {
+            if days.len() > 1 {
+                eprintln!("Only a single day can be watched at once!");
+                exit(1);
+            }
+            watch::watch(&args, &root(), days[0]);
+        }
+        Command::Examples => {
+            if !examples::examples(&args, &days) {
+                exit(1);
+            }
+        }
+        Command::Fetch | Command::Doctor | Command::New => unreachable!(),
+    }
+}
@@ -0,0 +1,206 @@
+use crate::fetch;
+use std::{fs, path::Path};
+use toml_edit::{DocumentMut, InlineTable, Value};
+
+const MANIFEST: &str = r#"[package]
+name = "day-{nn}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+lib = { version = "0.1.0", path = "../lib" }
+"#;
+
+const LIB: &str = r#"use lib::*;
+use std::fmt::Display;
+
+pub struct Day{nn};
+
+impl Solution for Day{nn} {
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.to_owned()
+    }
+
+    fn part1(_input: &Self::Input) -> impl Display {
+        0
+    }
+
+    fn part2(_input: &Self::Input) -> impl Display {
+        0
+    }
+}
+
+lib::register!(Day{nn});
+
+#[cfg(test)]
+mod test {
+    lib::example_tests!();
+}
+"#;
+
+const MAIN: &str = r#"fn main() {
+    lib::run(day_{nn}::day());
+}
+"#;
+
+/// Fails unless the given day is one of the days of the calendar.
+pub fn check_day(day: u8) -> Result<(), String> {
+    match day {
+        1..=25 => Ok(()),
+        _ => Err(format!("day {day} is not within 1..=25")),
+    }
+}
+
+/// Creates the crate of the given day from the templates, adds it to the
+/// workspace and registers it in the `days` crate. Afterwards, the input
+/// of the day is fetched.
+pub fn new(root: &Path, day: u8) -> Result<(), String> {
+    check_day(day)?;
+
+    let name = format!("day-{day:02}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{name} already exists"));
+    }
+
+    let template = |t: &str| t.replace("{nn}", &format!("{day:02}"));
+
+    fs::create_dir_all(dir.join("src")).map_err(|err| format!("failed to create {name}: {err}"))?;
+    write(&dir.join("Cargo.toml"), &template(MANIFEST))?;
+    write(&dir.join("src").join("lib.rs"), &template(LIB))?;
+    write(&dir.join("src").join("main.rs"), &template(MAIN))?;
+    write(&dir.join("challenge.txt"), "")?;
+
+    edit(&root.join("Cargo.toml"), |m| add_member(m, &name))?;
+    edit(&root.join("days").join("Cargo.toml"), |m| {
+        add_dependency(m, &name)
+    })?;
+    edit(&root.join("days").join("src").join("lib.rs"), |s| {
+        Ok(register(s, day))
+    })?;
+
+    println!("created {name}");
+
+    fetch::fetch(root, &[day]);
+    println!("Paste the puzzle text into {name}/challenge.txt and run `aoc examples {day}`.");
+
+    Ok(())
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|err| format!("failed to write {}: {err}", path.display()))
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Result<String, String>) -> Result<(), String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    write(path, &f(&content)?)
+}
+
+/// Adds the given crate to the members of the workspace manifest, keeping
+/// the days sorted regardless of gaps between them.
+fn add_member(manifest: &str, name: &str) -> Result<String, String> {
+    let mut doc: DocumentMut = manifest.parse().map_err(|err| format!("{err}"))?;
+    let members = doc["workspace"]["members"]
+        .as_array_mut()
+        .ok_or("workspace has no members")?;
+
+    if members.iter().any(|m| m.as_str() == Some(name)) {
+        return Ok(doc.to_string());
+    }
+
+    let idx = members
+        .iter()
+        .position(|m| {
+            m.as_str()
+                .is_some_and(|m| m.starts_with("day-") && m > name)
+        })
+        .unwrap_or(members.len());
+
+    let mut value = Value::from(name);
+    value.decor_mut().set_prefix("\n    ");
+    members.insert_formatted(idx, value);
+
+    Ok(doc.to_string())
+}
+
+/// Adds the given day crate as dependency of the `days` crate.
+fn add_dependency(manifest: &str, name: &str) -> Result<String, String> {
+    let mut doc: DocumentMut = manifest.parse().map_err(|err| format!("{err}"))?;
+    let deps = doc["dependencies"]
+        .as_table_mut()
+        .ok_or("days crate has no dependencies")?;
+
+    let mut dep = InlineTable::new();
+    dep.insert("version", "0.1.0".into());
+    dep.insert("path", format!("../{name}").into());
+    deps.insert(name, dep.into());
+    deps.sort_values_by(|a, _, b, _| {
+        (a.get().starts_with("day-"), a.get()).cmp(&(b.get().starts_with("day-"), b.get()))
+    });
+
+    Ok(doc.to_string())
+}
+
+/// Adds the given day to the registry of the `days` crate in order.
+fn register(source: &str, day: u8) -> String {
+    let entry = format!("        day_{day:02}::day(),");
+    let mut lines: Vec<_> = source.lines().collect();
+
+    if !lines.contains(&entry.as_str()) {
+        let end = lines
+            .iter()
+            .position(|l| l.trim() == "])")
+            .unwrap_or(lines.len());
+        let idx = lines[..end]
+            .iter()
+            .position(|l| l.trim().starts_with("day_") && *l > entry.as_str())
+            .unwrap_or(end);
+        lines.insert(idx, &entry);
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_add_member() {
+        let manifest =
+            "[workspace]\nmembers = [\n    \"lib\",\n    \"day-16\",\n    \"day-18\",\n]\n";
+        let expected =
+            "[workspace]\nmembers = [\n    \"lib\",\n    \"day-16\",\n    \"day-17\",\n    \"day-18\",\n]\n";
+        assert_eq!(add_member(manifest, "day-17").unwrap(), expected);
+        assert_eq!(add_member(expected, "day-17").unwrap(), expected);
+
+        let expected = "[workspace]\nmembers = [\n    \"lib\",\n    \"day-16\",\n    \"day-18\",\n    \"day-21\",\n]\n";
+        assert_eq!(add_member(manifest, "day-21").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_check_day() {
+        assert!(check_day(1).is_ok());
+        assert!(check_day(25).is_ok());
+        assert!(check_day(0).is_err());
+        assert!(check_day(26).is_err());
+    }
+
+    #[test]
+    fn test_register() {
+        let source = "fn f() {\n    Registry::new(vec![\n        day_16::day(),\n        day_18::day(),\n    ])\n}\n";
+        assert_eq!(
+            register(source, 17),
+            "fn f() {\n    Registry::new(vec![\n        day_16::day(),\n        day_17::day(),\n        day_18::day(),\n    ])\n}\n"
+        );
+        assert_eq!(
+            register(source, 21),
+            "fn f() {\n    Registry::new(vec![\n        day_16::day(),\n        day_18::day(),\n        day_21::day(),\n    ])\n}\n"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+};
+
+thread_local! {
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+/// A panic caught while running a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// Source location of the panic, e.g. `day-07/src/lib.rs:120:18`.
+    pub location: String,
+    /// The input line being processed when the panic occurred, as tracked
+    /// by [`lib::lines`].
+    pub line: Option<String>,
+}
+
+impl Panic {
+    /// Describes the panic, locating the offending line in the given input
+    /// if it is known.
+    pub fn describe(&self, input: Option<&str>) -> String {
+        let mut res = format!("panicked at {}: {}", self.location, self.message);
+
+        if let Some(line) = &self.line {
+            match input.and_then(|i| i.split('\n').position(|l| l == line)) {
+                Some(idx) => res += &format!("\n  while processing input line {}: {line}", idx + 1),
+                None => res += &format!("\n  while processing input line: {line}"),
+            }
+        }
+
+        res
+    }
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|m| m.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".into())
+}
+
+/// Installs a panic hook which records panics inside of [`catch`] instead
+/// of printing them. Any other panic is printed as usual, followed by the
+/// input line being processed. Workers print the bare [`Panic::describe`]
+/// instead, which is passed on by their parent.
+pub fn install(worker: bool) {
+    let default = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let panic = Panic {
+            message: message(info.payload()),
+            location: info.location().map(|l| l.to_string()).unwrap_or_default(),
+            line: lib::current_line(),
+        };
+
+        if CAPTURING.get() {
+            CAUGHT.set(Some(panic));
+            return;
+        }
+
+        if worker {
+            eprintln!("{}", panic.describe(None));
+            return;
+        }
+
+        default(info);
+        if let Some(line) = panic.line {
+            eprintln!("while processing input line: {line}");
+        }
+    }));
+}
+
+/// Calls the given function, returning the panic it caused if any.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Panic> {
+    CAPTURING.set(true);
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    CAPTURING.set(false);
+
+    res.map_err(|payload| {
+        CAUGHT.take().unwrap_or_else(|| Panic {
+            message: message(payload.as_ref()),
+            location: String::new(),
+            line: None,
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        let panic = Panic {
+            message: "should not happen".into(),
+            location: "day-07/src/lib.rs:120:18".into(),
+            line: Some("KKJ2Q 123".into()),
+        };
+
+        assert_eq!(
+            panic.describe(Some("32T3K 765\nKKJ2Q 123")),
+            "panicked at day-07/src/lib.rs:120:18: should not happen\n  while processing input line 2: KKJ2Q 123"
+        );
+        assert_eq!(
+            panic.describe(None),
+            "panicked at day-07/src/lib.rs:120:18: should not happen\n  while processing input line: KKJ2Q 123"
+        );
+    }
+}
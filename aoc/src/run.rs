@@ -0,0 +1,141 @@
+use crate::{
+    args::Args,
+    color,
+    sandbox::{self, Status},
+};
+use lib::{Answers, Day, Format, Input, Part, PartResult};
+
+/// Solves the given parts, passing each result to `on_result` as soon as
+/// it is known so that it is reported even if a later part panics.
+fn run_input(day: &Day, input: &Input, parts: &[Part], mut on_result: impl FnMut(PartResult)) {
+    let raw = match input.read() {
+        Ok(raw) => raw,
+        Err(err) => {
+            eprintln!("{}: {err}", day.name);
+            return;
+        }
+    };
+
+    let parsed = day.parse(&raw);
+
+    for &part in parts {
+        on_result(day.run(input.name(), &parsed, part));
+    }
+}
+
+fn report(args: &Args, results: &[PartResult], highlight: bool) {
+    let colored = highlight && args.color && args.format == Format::Text;
+
+    for res in results {
+        let line = res.format(args.format);
+        if colored {
+            println!("{}", color::paint(&line, color::MAGENTA, true));
+        } else {
+            println!("{line}");
+        }
+    }
+}
+
+/// The inputs to run a day with, each paired with whether its results
+/// should be highlighted. Without an explicit input, the test inputs
+/// (`test_input.txt` and all examples) are run first followed by the
+/// real input.
+fn inputs(args: &Args, day: &Day) -> Vec<(Input, bool)> {
+    if let Some(input) = &args.input {
+        return vec![(input.clone(), true)];
+    }
+
+    let mut inputs = vec![];
+    if day.dir().join("test_input.txt").exists() {
+        inputs.push((Input::day_file(day, "test_input.txt"), false));
+    }
+    inputs.extend(Input::examples(day).into_iter().map(|i| (i, false)));
+
+    if args.test {
+        if inputs.is_empty() {
+            eprintln!("{} has no test_input.txt or examples!", day.name);
+        }
+        return inputs;
+    }
+
+    inputs.push((Input::day_file(day, "input.txt"), true));
+    inputs
+}
+
+/// The name the answers of the given input are recorded under. Files
+/// inside of the day directory, e.g. examples passed via `--input`, are
+/// recorded by their path relative to it.
+fn answers_key(day: &Day, input: &Input) -> String {
+    input
+        .path()
+        .and_then(|p| p.strip_prefix(day.dir()).ok())
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or_else(|| input.name().into())
+}
+
+/// Runs the day in a separate process within the limits given by the
+/// arguments and reports any violation of them.
+fn run_isolated(args: &Args, day: &Day, headers: bool) {
+    let mut current = None;
+    let status = sandbox::run(args, day, args.input.as_ref(), |res| {
+        if headers && current.as_ref() != Some(&res.input) {
+            println!("{}:", res.input);
+            current = Some(res.input.clone());
+        }
+        let highlight = args.input.is_some() || res.input == "input.txt";
+        report(args, &[res], highlight);
+    });
+
+    report_status(args, day, &status);
+}
+
+/// Reports how running a day in a separate process failed, if it did.
+pub fn report_status(args: &Args, day: &Day, status: &Status) {
+    let line = match status {
+        Status::Ok => return,
+        Status::Timeout => format!("{}: TIMEOUT after {:.2?}", day.name, args.timeout.unwrap()),
+        Status::Oom => format!("{}: OOM at {} MiB", day.name, args.memory.unwrap() >> 20),
+        Status::Panic(err) => format!("{}: PANIC\n{err}", day.name),
+        Status::Failed(err) => format!("{}: FAILED\n{err}", day.name),
+    };
+
+    if args.format == Format::Text {
+        println!("{}", color::paint(&line, color::RED, args.color));
+    } else {
+        eprintln!("{line}");
+    }
+}
+
+pub fn run(args: &Args, days: &[&Day]) {
+    if let Some(header) = args.format.header() {
+        println!("{header}");
+    }
+
+    for day in days {
+        if days.len() > 1 && args.format == Format::Text {
+            println!("--- {} ---", day.name);
+        }
+
+        let inputs = inputs(args, day);
+        let headers = inputs.len() > 1 && args.format == Format::Text;
+
+        if sandbox::enabled(args) {
+            run_isolated(args, day, headers);
+            continue;
+        }
+
+        let answers = Answers::load(&day.dir());
+        for (input, highlight) in &inputs {
+            if headers {
+                println!("{}:", input.name());
+            }
+
+            let parts: Vec<_> = answers
+                .parts(&answers_key(day, input))
+                .into_iter()
+                .filter(|&p| args.part.is_none_or(|part| part == p))
+                .collect();
+            run_input(day, input, &parts, |res| report(args, &[res], *highlight));
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::args::Args;
+use core::fmt;
+use lib::{Day, Input, PartResult};
+use std::{
+    env,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        OnceLock,
+    },
+    thread,
+    time::Instant,
+};
+
+/// How running a day in a separate process ended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// The day exceeded the `--timeout` given.
+    Timeout,
+    /// The day exceeded the `--memory` limit given.
+    Oom,
+    /// The day panicked with the given message.
+    Panic(String),
+    /// The process failed otherwise with the given error output.
+    Failed(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "OK"),
+            Status::Timeout => write!(f, "TIMEOUT"),
+            Status::Oom => write!(f, "OOM"),
+            Status::Panic(_) => write!(f, "PANIC"),
+            Status::Failed(_) => write!(f, "FAILED"),
+        }
+    }
+}
+
+/// Whether days are run in separate processes to enforce the limits.
+pub fn enabled(args: &Args) -> bool {
+    !args.worker && (args.timeout.is_some() || args.memory.is_some())
+}
+
+fn worker_args(args: &Args, day: &Day, input: Option<&Input>) -> Vec<String> {
+    let mut res = vec![
+        "run".into(),
+        day.number.to_string(),
+        "--worker".into(),
+        "--format".into(),
+        "tsv".into(),
+    ];
+
+    if let Some(part) = args.part {
+        res.extend(["--part".into(), part.to_string()]);
+    }
+    if args.test {
+        res.push("--test".into());
+    }
+    if let Some(trace) = &args.trace {
+        res.extend(["--trace".into(), trace.clone()]);
+    }
+    if let Some(memory) = args.memory {
+        res.extend(["--memory".into(), (memory >> 20).to_string()]);
+    }
+    match input.map(|i| i.path()) {
+        Some(Some(path)) => res.extend(["--input".into(), path.display().to_string()]),
+        Some(None) => res.push("--stdin".into()),
+        None => {}
+    }
+
+    res
+}
+
+/// The path of the running binary which the workers are started from. It
+/// is resolved once, so that it still names the binary after it has been
+/// replaced by a rebuild, which the path of the running process does not.
+pub fn exe() -> &'static Path {
+    static EXE: OnceLock<PathBuf> = OnceLock::new();
+    EXE.get_or_init(|| env::current_exe().unwrap())
+}
+
+/// Runs the given day in a child process which is killed once it exceeds
+/// the timeout and aborts once it exceeds the memory limit. Without an
+/// explicit input, the child runs the inputs `aoc run` would pick. The
+/// results of the day are passed to `on_result` as soon as the child
+/// reports them, while its trace events are passed through to stderr.
+pub fn run(
+    args: &Args,
+    day: &Day,
+    input: Option<&Input>,
+    mut on_result: impl FnMut(PartResult),
+) -> Status {
+    let stdin = match input.is_some_and(|i| i.path().is_none()) {
+        true => Stdio::inherit(),
+        false => Stdio::null(),
+    };
+
+    let child = Command::new(exe())
+        .args(worker_args(args, day, input))
+        .stdin(stdin)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(err) => return Status::Failed(format!("failed to start worker: {err}")),
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    thread::spawn(move || {
+        for line in stdout.lines().map_while(Result::ok) {
+            if let Some(res) = PartResult::parse_tsv(&line) {
+                if tx.send(res).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    let stderr = BufReader::new(child.stderr.take().unwrap());
+    let stderr = thread::spawn(move || {
+        let mut res = String::new();
+        for line in stderr.lines().map_while(Result::ok) {
+            match lib::is_trace_line(&line) {
+                true => eprintln!("{line}"),
+                false => res += &format!("{line}\n"),
+            }
+        }
+        res
+    });
+
+    let deadline = args.timeout.map(|t| Instant::now() + t);
+    loop {
+        let res = match deadline {
+            Some(deadline) => rx.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match res {
+            Ok(res) => on_result(res),
+            Err(RecvTimeoutError::Disconnected) => break,
+            Err(RecvTimeoutError::Timeout) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Status::Timeout;
+            }
+        }
+    }
+
+    let status = child.wait();
+    let stderr = stderr.join().unwrap_or_default();
+
+    match status {
+        Ok(status) if status.success() => Status::Ok,
+        _ if args.memory.is_some() && stderr.contains("memory allocation of") => Status::Oom,
+        _ => match panic_message(&stderr) {
+            Some(panic) => Status::Panic(panic.into()),
+            None => Status::Failed(stderr.trim().into()),
+        },
+    }
+}
+
+/// The panic reported by a worker, which starts at the line printed by its
+/// panic hook and may follow any other output of the day.
+fn panic_message(stderr: &str) -> Option<&str> {
+    let (idx, _) = stderr
+        .match_indices("panicked at")
+        .find(|&(i, _)| i == 0 || stderr.as_bytes()[i - 1] == b'\n')?;
+    Some(stderr[idx..].trim())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_panic_message() {
+        let panic = "panicked at day-07/src/lib.rs:120:18: boom\n  while processing input line 2: KKJ2Q 123\n";
+        assert_eq!(panic_message(panic), Some(panic.trim()));
+        assert_eq!(
+            panic_message(&format!("warning: odd input\n{panic}")),
+            Some(panic.trim())
+        );
+        assert_eq!(panic_message("error: not panicked at all\n"), None);
+    }
+}
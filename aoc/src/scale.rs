@@ -0,0 +1,133 @@
+use crate::{
+    args::Args,
+    bench::{bench_day, Measurement},
+    color, panic,
+    table::Table,
+};
+use lib::Day;
+use std::time::Duration;
+
+const BAR_WIDTH: usize = 40;
+
+/// The measurements of all phases of a day for a single input size.
+struct Point {
+    size: usize,
+    measurements: Vec<Measurement>,
+}
+
+impl Point {
+    fn total(&self) -> Duration {
+        self.measurements.iter().map(|m| m.stats.median).sum()
+    }
+}
+
+/// Exponent `k` of `time ~ size^k` between both points.
+fn growth(a: &Point, b: &Point) -> f64 {
+    let time = b.total().as_secs_f64() / a.total().as_secs_f64();
+    time.ln() / (b.size as f64 / a.size as f64).ln()
+}
+
+fn print_table(points: &[Point], colored: bool) {
+    let phases: Vec<_> = points[0]
+        .measurements
+        .iter()
+        .map(|m| m.phase.to_uppercase())
+        .collect();
+
+    let mut header = vec!["SIZE"];
+    header.extend(phases.iter().map(String::as_str));
+    header.extend(["TOTAL", "GROWTH", ""]);
+    let mut table = Table::new(&header);
+
+    let max = points.iter().map(Point::total).max().unwrap_or_default();
+    for (i, point) in points.iter().enumerate() {
+        let mut row = vec![point.size.to_string()];
+        row.extend(
+            point
+                .measurements
+                .iter()
+                .map(|m| format!("{:.2?}", m.stats.median)),
+        );
+
+        let total = point.total();
+        let bar = (total.as_secs_f64() / max.as_secs_f64().max(f64::MIN_POSITIVE)
+            * BAR_WIDTH as f64)
+            .round() as usize;
+        row.extend([
+            format!("{total:.2?}"),
+            match i {
+                0 => "-".into(),
+                _ => format!("n^{:.2}", growth(&points[i - 1], point)),
+            },
+            "#".repeat(bar.max(1)),
+        ]);
+        table.row(row);
+    }
+
+    table.print(colored);
+}
+
+fn json_points(day: &Day, seed: u64, points: &[Point]) -> Vec<String> {
+    points
+        .iter()
+        .flat_map(|p| {
+            p.measurements.iter().map(move |m| {
+                format!(
+                    "  {{\"day\": \"{}\", \"size\": {}, \"seed\": {seed}, \"phase\": \"{}\", \
+                     \"median_ns\": {}, \"mean_ns\": {}}}",
+                    day.name,
+                    p.size,
+                    m.phase,
+                    m.stats.median.as_nanos(),
+                    m.stats.mean.as_nanos()
+                )
+            })
+        })
+        .collect()
+}
+
+/// Benchmarks the given days with inputs of increasing size produced by
+/// their generators and prints how the time grows with the size. Days
+/// without a generator are skipped. Returns whether all generated inputs
+/// could be solved.
+pub fn scale(args: &Args, days: &[&Day]) -> bool {
+    let days: Vec<_> = days.iter().filter(|d| d.sizes().is_some()).collect();
+    if days.is_empty() {
+        eprintln!("None of the days has an input generator!");
+        return false;
+    }
+
+    let mut ok = true;
+    let mut json = vec![];
+
+    for day in days {
+        let sizes = args.sizes.as_deref().or(day.sizes()).unwrap_or_default();
+        let mut points = vec![];
+
+        for &size in sizes {
+            let input = day.generate(size, args.seed).unwrap();
+            match panic::catch(|| bench_day(day, &input, args)) {
+                Ok(measurements) => points.push(Point { size, measurements }),
+                Err(panic) => {
+                    let err = format!("{} size {size}: {}", day.name, panic.describe(Some(&input)));
+                    eprintln!("{}", color::paint(&err, color::RED, args.color));
+                    ok = false;
+                }
+            }
+        }
+
+        if args.json {
+            json.extend(json_points(day, args.seed, &points));
+        } else if !points.is_empty() {
+            println!("--- {} ---", day.name);
+            print_table(&points, args.color);
+            println!();
+        }
+    }
+
+    if args.json {
+        println!("[\n{}\n]", json.join(",\n"));
+    }
+
+    ok
+}
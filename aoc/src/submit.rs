@@ -0,0 +1,437 @@
+use crate::{
+    args::Args,
+    color,
+    fetch::{self, Config, FetchError, USER_AGENT},
+};
+use core::fmt;
+use lib::{Answer, Answers, Day, Input, Part};
+use std::{
+    error::Error,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The verdict of the puzzle server on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            x => Err(format!("invalid verdict: {x}")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The server asks to wait before submitting again.
+    TooRecent(String),
+    /// The part has been solved already or is not unlocked yet.
+    WrongLevel,
+    /// The response carries none of the known verdicts.
+    Unexpected(String),
+    Fetch(FetchError),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::TooRecent(wait) => write!(f, "answer submitted too recently; {wait}"),
+            SubmitError::WrongLevel => write!(f, "part is solved already or not unlocked yet"),
+            SubmitError::Unexpected(body) => write!(f, "unexpected response: {body}"),
+            SubmitError::Fetch(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+impl From<FetchError> for SubmitError {
+    fn from(err: FetchError) -> Self {
+        SubmitError::Fetch(err)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(err: io::Error) -> Self {
+        SubmitError::Fetch(err.into())
+    }
+}
+
+/// A submitted answer together with the verdict of the server, as stored
+/// in the `guesses.txt` of a day.
+///
+/// ```text
+/// # timestamp part answer verdict
+/// 1701417600 1 54321 too-high
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guess {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Guess {
+    const FILE: &'static str = "guesses.txt";
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut split = line.split_ascii_whitespace();
+        Some(Self {
+            timestamp: split.next()?.parse().ok()?,
+            part: split.next()?.parse().ok()?,
+            answer: split.next()?.into(),
+            verdict: split.next()?.parse().ok()?,
+        })
+    }
+
+    pub fn load(dir: &Path) -> Vec<Self> {
+        fs::read_to_string(dir.join(Self::FILE))
+            .unwrap_or_default()
+            .lines()
+            .filter(|l| !l.starts_with('#'))
+            .filter_map(Self::parse)
+            .collect()
+    }
+
+    fn append(&self, dir: &Path) -> io::Result<()> {
+        let file = dir.join(Self::FILE);
+        let header = match file.exists() {
+            true => "",
+            false => "# timestamp part answer verdict\n",
+        };
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(file)?
+            .write_all(
+                format!(
+                    "{header}{} {} {} {}\n",
+                    self.timestamp, self.part, self.answer, self.verdict
+                )
+                .as_bytes(),
+            )
+    }
+}
+
+fn solved(guesses: &[Guess], part: Part) -> bool {
+    guesses
+        .iter()
+        .any(|g| g.part == part && g.verdict == Verdict::Correct)
+}
+
+/// Checks the answer against the earlier guesses of the part, refusing
+/// it if the part is solved already, if the same answer was rejected
+/// before, or if it lies outside of the bounds known from guesses which
+/// were too high or too low.
+pub fn vet(guesses: &[Guess], part: Part, answer: &str) -> Result<(), String> {
+    let guesses = guesses.iter().filter(|g| g.part == part);
+    let number = answer.parse::<i128>().ok();
+
+    for guess in guesses {
+        let bound = guess.answer.parse::<i128>().ok();
+        match guess.verdict {
+            Verdict::Correct => {
+                return Err(format!(
+                    "part {part} was solved with {} already",
+                    guess.answer
+                ))
+            }
+            _ if guess.answer == answer => {
+                return Err(format!(
+                    "{answer} was rejected as {} already",
+                    guess.verdict
+                ))
+            }
+            Verdict::TooHigh if number.zip(bound).is_some_and(|(n, b)| n >= b) => {
+                return Err(format!(
+                    "{answer} is not lower than {}, which is too high",
+                    guess.answer
+                ))
+            }
+            Verdict::TooLow if number.zip(bound).is_some_and(|(n, b)| n <= b) => {
+                return Err(format!(
+                    "{answer} is not higher than {}, which is too low",
+                    guess.answer
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_verdict(body: &str) -> Result<Verdict, SubmitError> {
+    if body.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if body.contains("your answer is too high") {
+        Ok(Verdict::TooHigh)
+    } else if body.contains("your answer is too low") {
+        Ok(Verdict::TooLow)
+    } else if body.contains("That's not the right answer") {
+        Ok(Verdict::Wrong)
+    } else if body.contains("You gave an answer too recently") {
+        let wait = body
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map_or("wait a moment".into(), |(wait, _)| format!("{wait} left"));
+        Err(SubmitError::TooRecent(wait))
+    } else if body.contains("You don't seem to be solving the right level") {
+        Err(SubmitError::WrongLevel)
+    } else {
+        Err(SubmitError::Unexpected(body.trim().into()))
+    }
+}
+
+/// Posts answers to the puzzle server, sharing the throttle of the
+/// [`fetch::Fetcher`].
+pub struct Submitter {
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+    throttle: Duration,
+}
+
+impl Submitter {
+    pub fn new(base_url: &str, session: &str, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.into(),
+            cache_dir: cache_dir.into(),
+            throttle: fetch::THROTTLE,
+        }
+    }
+
+    pub fn with_throttle(mut self, throttle: Duration) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Verdict, SubmitError> {
+        fetch::throttle(&self.cache_dir, self.throttle)?;
+
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let res = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let body = match res {
+            Ok(res) => res.into_string()?,
+            Err(ureq::Error::Status(code, res)) => {
+                let body = res.into_string().unwrap_or_default();
+                return Err(FetchError::Status(code, body.trim().into()).into());
+            }
+            Err(err) => return Err(FetchError::Transport(err.to_string()).into()),
+        };
+
+        parse_verdict(&body)
+    }
+}
+
+/// Solves a part of the given day with its real input and submits the
+/// answer, unless it is refused by [`vet`]. Without `--part`, the first
+/// part which is not solved yet is submitted. Correct answers are added
+/// to the `answers.txt` of the day. Returns whether the answer was correct.
+pub fn submit(args: &Args, root: &Path, day: &Day) -> bool {
+    let dir = day.dir();
+    let guesses = Guess::load(&dir);
+    let answers = Answers::load(&dir);
+    let recorded = |part| answers.get("input.txt").and_then(|a| a.part(part));
+
+    let part = match args.part {
+        Some(part) => part,
+        None => match Part::ALL
+            .into_iter()
+            .find(|&p| recorded(p).is_none() && !solved(&guesses, p))
+        {
+            Some(part) => part,
+            None => {
+                println!("{}: both parts are solved already", day.name);
+                return true;
+            }
+        },
+    };
+
+    let config = Config::load(root);
+    let Some(session) = &config.session else {
+        eprintln!("{}", FetchError::MissingSession);
+        return false;
+    };
+
+    let input = Input::day_file(day, "input.txt");
+    let raw = match input.read() {
+        Ok(raw) => raw,
+        Err(err) => {
+            eprintln!("{}: {err}", day.name);
+            return false;
+        }
+    };
+    let res = day.run(input.name(), &day.parse(&raw), part);
+    println!("{}", res.format(args.format));
+
+    if let Some(recorded) = recorded(part) {
+        eprintln!(
+            "{}: refusing to submit, part {part} is recorded as {recorded} already",
+            day.name
+        );
+        return false;
+    }
+    if let Err(reason) = vet(&guesses, part, &res.answer) {
+        eprintln!("{}: refusing to submit, {reason}", day.name);
+        return false;
+    }
+
+    let submitter = Submitter::new(
+        &config.base_url,
+        session,
+        root.join(".cache").join("inputs"),
+    )
+    .with_throttle(config.throttle);
+    let verdict = match submitter.submit(config.year, day.number, part, &res.answer) {
+        Ok(verdict) => verdict,
+        Err(err) => {
+            eprintln!("{}: {err}", day.name);
+            return false;
+        }
+    };
+
+    let guess = Guess {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        part,
+        answer: res.answer.clone(),
+        verdict,
+    };
+    if let Err(err) = guess.append(&dir) {
+        eprintln!("{}: failed to record guess: {err}", day.name);
+    }
+
+    let c = match verdict {
+        Verdict::Correct => color::GREEN,
+        _ => color::RED,
+    };
+    println!(
+        "{}: {} is {}",
+        day.name,
+        res.answer,
+        color::paint(&verdict.to_string(), c, args.color)
+    );
+
+    if verdict != Verdict::Correct {
+        return false;
+    }
+
+    let answer = Answer {
+        input: "input.txt".into(),
+        part1: (part == Part::One).then(|| res.answer.clone()),
+        part2: (part == Part::Two).then(|| res.answer.clone()),
+    };
+    if let Err(err) = Answers::merge(&dir, &[answer]) {
+        eprintln!("{}: failed to record answer: {err}", day.name);
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fetch::test::stub;
+    use std::env;
+
+    fn guess(part: Part, answer: &str, verdict: Verdict) -> Guess {
+        Guess {
+            timestamp: 0,
+            part,
+            answer: answer.into(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_vet() {
+        let guesses = [
+            guess(Part::One, "100", Verdict::TooHigh),
+            guess(Part::One, "20", Verdict::TooLow),
+            guess(Part::One, "50", Verdict::Wrong),
+            guess(Part::Two, "7", Verdict::Correct),
+        ];
+
+        assert!(vet(&guesses, Part::One, "42").is_ok());
+        assert!(vet(&guesses, Part::One, "50").is_err());
+        assert!(vet(&guesses, Part::One, "100").is_err());
+        assert!(vet(&guesses, Part::One, "150").is_err());
+        assert!(vet(&guesses, Part::One, "20").is_err());
+        assert!(vet(&guesses, Part::Two, "8").is_err());
+    }
+
+    #[test]
+    fn test_guess() {
+        let guess = Guess::parse("1701417600 1 54321 too-high").unwrap();
+        assert_eq!(guess.timestamp, 1701417600);
+        assert_eq!(guess.part, Part::One);
+        assert_eq!(guess.answer, "54321");
+        assert_eq!(guess.verdict, Verdict::TooHigh);
+        assert_eq!(Guess::parse("# timestamp part answer verdict"), None);
+    }
+
+    #[test]
+    fn test_submit() {
+        let body = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+        let (addr, handle) = stub(response.leak());
+        let dir = env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+
+        let submitter = Submitter::new(&addr, "abc", &dir).with_throttle(Duration::ZERO);
+        let verdict = submitter.submit(2023, 5, Part::Two, "42").unwrap();
+        assert_eq!(verdict, Verdict::TooLow);
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/5/answer "));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+        assert!(request.ends_with("level=2&answer=42"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    args::Args,
+    color, panic,
+    sandbox::{self, Status},
+    table::Table,
+};
+use lib::{Day, Input, Part, PartResult};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// The outcome of running a single day.
+struct Outcome {
+    results: Vec<PartResult>,
+    status: Status,
+}
+
+fn input(args: &Args, day: &Day) -> Input {
+    args.input
+        .clone()
+        .unwrap_or_else(|| Input::day_file(day, "input.txt"))
+}
+
+/// Runs the day within the current process, turning a panic into
+/// [`Status::Panic`] pointing at the input line being processed.
+fn run_day(args: &Args, day: &Day, parts: &[Part]) -> Outcome {
+    let input = input(args, day);
+    let raw = match input.read() {
+        Ok(raw) => raw,
+        Err(err) => {
+            return Outcome {
+                results: vec![],
+                status: Status::Failed(err.to_string()),
+            }
+        }
+    };
+
+    let mut results = vec![];
+    let res = panic::catch(|| {
+        let parsed = day.parse(&raw);
+        for &part in parts {
+            results.push(day.run(input.name(), &parsed, part));
+        }
+    });
+
+    let status = match res {
+        Ok(()) => Status::Ok,
+        Err(panic) => Status::Panic(panic.describe(Some(&raw))),
+    };
+
+    Outcome { results, status }
+}
+
+/// Runs the day in a worker process within the limits of the arguments.
+fn run_isolated(args: &Args, day: &Day) -> Outcome {
+    let mut results = vec![];
+    let status = sandbox::run(args, day, Some(&input(args, day)), |res| results.push(res));
+    Outcome { results, status }
+}
+
+/// Runs the given days concurrently on `args.jobs` threads and prints a
+/// summary table of all parts once every day has finished. A day that
+/// panics, or violates the limits, is reported without affecting the
+/// others. Returns whether all days succeeded.
+pub fn summary(args: &Args, days: &[&Day]) -> bool {
+    let parts: Vec<_> = Part::ALL
+        .into_iter()
+        .filter(|&p| args.part.is_none_or(|part| part == p))
+        .collect();
+
+    let next = AtomicUsize::new(0);
+    let outcomes = Mutex::new(Vec::with_capacity(days.len()));
+
+    thread::scope(|s| {
+        for _ in 0..args.jobs.min(days.len()) {
+            s.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = days.get(idx) else {
+                    break;
+                };
+
+                let outcome = match sandbox::enabled(args) {
+                    true => run_isolated(args, day),
+                    false => run_day(args, day, &parts),
+                };
+                outcomes.lock().unwrap().push((idx, outcome));
+            });
+        }
+    });
+
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by_key(|(idx, _)| *idx);
+
+    let mut table = Table::new(&["DAY", "PART", "ANSWER", "TIME", "STATUS"]);
+    let mut failures = vec![];
+
+    for (idx, outcome) in &outcomes {
+        let day = days[*idx];
+
+        for &part in &parts {
+            let row = match outcome.results.iter().find(|r| r.part == part) {
+                Some(res) => (res.answer.clone(), format!("{:.2?}", res.time), Status::Ok),
+                None => ("-".into(), "-".into(), outcome.status.clone()),
+            };
+
+            table.row(vec![
+                day.name.into(),
+                part.to_string(),
+                row.0,
+                row.1,
+                row.2.to_string(),
+            ]);
+            table.color_last(match row.2 {
+                Status::Ok => color::GREEN,
+                _ => color::RED,
+            });
+        }
+
+        match &outcome.status {
+            Status::Ok => {}
+            Status::Timeout => failures.push(format!(
+                "{}: TIMEOUT after {:.2?}",
+                day.name,
+                args.timeout.unwrap()
+            )),
+            Status::Oom => failures.push(format!(
+                "{}: OOM at {} MiB",
+                day.name,
+                args.memory.unwrap() >> 20
+            )),
+            Status::Panic(err) | Status::Failed(err) => {
+                failures.push(format!("{}: {err}", day.name))
+            }
+        }
+    }
+
+    table.print(args.color);
+    for failure in &failures {
+        println!("\n{}", color::paint(failure, color::RED, args.color));
+    }
+
+    failures.is_empty()
+}
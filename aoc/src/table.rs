@@ -0,0 +1,58 @@
+use crate::color;
+
+/// A plain text table with left-aligned columns. Cells can optionally be
+/// colored without affecting the column alignment.
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<(String, Option<&'static str>)>>,
+}
+
+impl Table {
+    pub fn new(header: &[&str]) -> Self {
+        Self {
+            header: header.iter().map(|h| h.to_string()).collect(),
+            rows: vec![],
+        }
+    }
+
+    pub fn row(&mut self, cells: Vec<String>) {
+        self.rows
+            .push(cells.into_iter().map(|c| (c, None)).collect());
+    }
+
+    /// Colors the last cell of the most recently added row.
+    pub fn color_last(&mut self, color: &'static str) {
+        if let Some(cell) = self.rows.last_mut().and_then(|r| r.last_mut()) {
+            cell.1 = Some(color);
+        }
+    }
+
+    pub fn print(&self, colored: bool) {
+        let mut widths: Vec<_> = self.header.iter().map(|h| h.len()).collect();
+        for row in &self.rows {
+            for (i, (cell, _)) in row.iter().enumerate() {
+                widths[i] = widths[i].max(cell.chars().count());
+            }
+        }
+
+        let line = |cells: Vec<(&str, Option<&str>)>| {
+            let cells: Vec<_> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(&(cell, c), &w)| {
+                    let padded = format!("{cell:<w$}");
+                    match c {
+                        Some(c) => color::paint(&padded, c, colored),
+                        None => padded,
+                    }
+                })
+                .collect();
+            println!("{}", cells.join("  ").trim_end());
+        };
+
+        line(self.header.iter().map(|h| (h.as_str(), None)).collect());
+        for row in &self.rows {
+            line(row.iter().map(|(c, col)| (c.as_str(), *col)).collect());
+        }
+    }
+}
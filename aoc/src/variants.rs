@@ -0,0 +1,81 @@
+use crate::{args::Args, bench::measure, color, table::Table};
+use lib::{Day, Input, Part};
+
+/// Runs the default implementation and every registered variant of each
+/// part, benchmarks them against each other and checks that they all
+/// agree on the answer. Returns whether no variant disagreed.
+pub fn variants(args: &Args, days: &[&Day]) -> bool {
+    let mut table = Table::new(&[
+        "DAY", "PART", "VARIANT", "ANSWER", "MEAN", "MEDIAN", "STDDEV", "MIN", "MAX", "STATUS",
+    ]);
+    let mut ok = true;
+
+    for day in days {
+        let variants = day.variants();
+        if variants.is_empty() {
+            continue;
+        }
+
+        let input = match Input::day_file(day, "input.txt").read() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{}: {err}", day.name);
+                continue;
+            }
+        };
+
+        let parsed = day.parse(&input);
+
+        for part in Part::ALL {
+            if args.part.is_some_and(|p| p != part) {
+                continue;
+            }
+
+            let solve = || day.part(&parsed, part);
+            let mut results = vec![(
+                "default",
+                solve(),
+                measure(args.warmup, args.iterations, solve),
+            )];
+
+            for v in variants.iter().filter(|v| v.part == part) {
+                let solve = || v.solve(&parsed);
+                results.push((
+                    v.name,
+                    solve(),
+                    measure(args.warmup, args.iterations, solve),
+                ));
+            }
+
+            let expected = results[0].1.clone();
+
+            for (name, answer, stats) in results {
+                let (status, c) = if name == "default" {
+                    ("-", color::RESET)
+                } else if answer == expected {
+                    ("MATCH", color::GREEN)
+                } else {
+                    ok = false;
+                    ("MISMATCH", color::RED)
+                };
+
+                table.row(vec![
+                    day.name.into(),
+                    part.to_string(),
+                    name.into(),
+                    answer,
+                    format!("{:.2?}", stats.mean),
+                    format!("{:.2?}", stats.median),
+                    format!("{:.2?}", stats.stddev),
+                    format!("{:.2?}", stats.min),
+                    format!("{:.2?}", stats.max),
+                    status.into(),
+                ]);
+                table.color_last(c);
+            }
+        }
+    }
+
+    table.print(args.color);
+    ok
+}
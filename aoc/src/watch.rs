@@ -0,0 +1,159 @@
+use crate::{args::Args, color, run::report_status, sandbox};
+use lib::{Day, Format, Part};
+use std::{
+    collections::{BTreeMap, HashMap},
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification times of the watched files of a day.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+fn collect(path: &Path, snapshot: &mut Snapshot) {
+    let Ok(meta) = fs::metadata(path) else {
+        return;
+    };
+
+    if meta.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            collect(&entry.path(), snapshot);
+        }
+    } else if let Ok(modified) = meta.modified() {
+        snapshot.insert(path.into(), modified);
+    }
+}
+
+/// The sources of the day as well as its real, test and example inputs.
+fn snapshot(day: &Day) -> Snapshot {
+    let dir = day.dir();
+    let mut snapshot = Snapshot::new();
+
+    collect(&Path::new(day.manifest_dir).join("src"), &mut snapshot);
+    for file in ["input.txt", "test_input.txt", "examples"] {
+        collect(&dir.join(file), &mut snapshot);
+    }
+
+    snapshot
+}
+
+/// Paths added, removed or modified between both snapshots.
+fn changes(old: &Snapshot, new: &Snapshot) -> Vec<PathBuf> {
+    let mut paths: Vec<_> = new
+        .iter()
+        .filter(|(p, t)| old.get(*p) != Some(t))
+        .map(|(p, _)| p.clone())
+        .chain(old.keys().filter(|p| !new.contains_key(*p)).cloned())
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Rebuilds the runner, including the day, with the profile and features
+/// of the running binary.
+fn build() -> bool {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut cmd = Command::new(cargo);
+    cmd.args(["build", "-q", "-p", "aoc"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"));
+
+    if !cfg!(debug_assertions) {
+        cmd.arg("--release");
+    }
+    if cfg!(feature = "trace") {
+        cmd.args(["--features", "trace"]);
+    }
+
+    cmd.status().is_ok_and(|s| s.success())
+}
+
+/// Runs the day in a worker process of the freshly built binary and prints
+/// its answers along with the ones of the previous run they differ from.
+fn run(args: &Args, day: &Day, previous: &mut HashMap<(String, Part), String>) {
+    let mut current = None;
+
+    let status = sandbox::run(args, day, args.input.as_ref(), |res| {
+        if current.as_ref() != Some(&res.input) {
+            println!("{}:", res.input);
+            current = Some(res.input.clone());
+        }
+
+        let line = res.format(Format::Text);
+        let key = (res.input.clone(), res.part);
+        match previous.insert(key, res.answer.clone()) {
+            Some(prev) if prev != res.answer => println!(
+                "{}",
+                color::paint(&format!("{line} was {prev}"), color::YELLOW, args.color)
+            ),
+            _ if res.input == "input.txt" => {
+                println!("{}", color::paint(&line, color::MAGENTA, args.color))
+            }
+            _ => println!("{line}"),
+        }
+    });
+
+    report_status(args, day, &status);
+}
+
+fn clock() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        % 86400;
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+/// Polls the sources and inputs of the day and, whenever any of them
+/// changes, rebuilds the runner and reruns the day with its examples
+/// followed by the real input. Answers differing from the previous run are
+/// highlighted. Runs until interrupted.
+pub fn watch(args: &Args, root: &Path, day: &Day) {
+    let mut last = Snapshot::new();
+    let mut previous = HashMap::new();
+    sandbox::exe();
+
+    loop {
+        let snapshot = snapshot(day);
+        let changes = changes(&last, &snapshot);
+        if changes.is_empty() {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+        let first = last.is_empty();
+        last = snapshot;
+
+        let changed = match first {
+            true => "started".to_owned(),
+            false => changes
+                .iter()
+                .map(|p| p.strip_prefix(root).unwrap_or(p).display().to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+        println!(
+            "{}",
+            color::paint(
+                &format!("[{} UTC] {}: {changed}", clock(), day.name),
+                color::GREEN,
+                args.color
+            )
+        );
+
+        if build() {
+            run(args, day, &mut previous);
+        } else {
+            println!("{}", color::paint("build failed", color::RED, args.color));
+        }
+        println!("watching {} for changes...\n", day.name);
+    }
+}
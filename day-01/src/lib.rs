@@ -0,0 +1,77 @@
+use lib::*;
+use std::fmt::Display;
+
+pub fn get_digits(line: &str, with_literals: bool) -> String {
+    let mut number = String::new();
+    let mut buff = String::new();
+
+    let literals = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    for c in line.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            buff.clear();
+            continue;
+        }
+
+        if with_literals {
+            buff.push(c);
+            let literal = literals
+                .iter()
+                .enumerate()
+                .find(|(_, &lit)| buff.ends_with(lit))
+                .map(|(idx, _)| idx + 1);
+
+            if let Some(literal) = literal {
+                number.push_str(&literal.to_string());
+            }
+        }
+    }
+
+    number.parse().unwrap()
+}
+
+pub fn get_combined_number(line: &str, with_literals: bool) -> u32 {
+    let digits: Vec<char> = get_digits(line, with_literals).chars().collect();
+
+    let number = if digits.len() == 1 {
+        String::from_utf8(vec![digits[0] as u8, digits[0] as u8])
+    } else {
+        String::from_utf8(vec![digits[0] as u8, *digits.last().unwrap() as u8])
+    };
+
+    number.unwrap().parse().unwrap()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Self::Input {
+        lines(input).map(String::from).collect()
+    }
+
+    fn part1(lines: &Self::Input) -> impl Display {
+        lines
+            .iter()
+            .map(|l| get_combined_number(l, false))
+            .sum::<u32>()
+    }
+
+    fn part2(lines: &Self::Input) -> impl Display {
+        lines
+            .iter()
+            .map(|l| get_combined_number(l, true))
+            .sum::<u32>()
+    }
+}
+
+lib::register!(Day01);
+
+#[cfg(test)]
+mod test {
+    lib::example_tests!(example, example_2);
+}
@@ -0,0 +1,101 @@
+use lib::*;
+use std::fmt::Display;
+
+#[derive(Default, Debug)]
+pub struct Cubes {
+    pub red: u32,
+    pub blue: u32,
+    pub green: u32,
+}
+
+impl Cubes {
+    pub fn parse(raw: &str) -> Self {
+        let mut round = Self::default();
+
+        for cube in raw.split(',').map(str::trim) {
+            let (n, color) = cube.split_once(' ').unwrap();
+            let n = n.parse().unwrap();
+            match color {
+                "blue" => round.blue = n,
+                "green" => round.green = n,
+                "red" => round.red = n,
+                _ => panic!("unexpected color: {color}"),
+            }
+        }
+
+        round
+    }
+
+    pub fn power(&self) -> u32 {
+        self.blue * self.green * self.red
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Cubes>,
+}
+
+impl Game {
+    pub fn parse(line: &str) -> Self {
+        let idx = line.chars().position(|c| c == ':').unwrap();
+        let id = line[5..idx].parse().unwrap();
+
+        let rounds = line[idx + 1..].split(';').map(Cubes::parse).collect();
+
+        Self { id, rounds }
+    }
+
+    pub fn max_cubes(&self) -> Cubes {
+        Cubes {
+            red: self.rounds.iter().map(|r| r.red).max().unwrap(),
+            green: self.rounds.iter().map(|r| r.green).max().unwrap(),
+            blue: self.rounds.iter().map(|r| r.blue).max().unwrap(),
+        }
+    }
+
+    pub fn is_possible(&self, max: &Cubes) -> bool {
+        self.rounds
+            .iter()
+            .all(|r| r.blue <= max.blue && r.red <= max.red && r.green <= max.green)
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Self::Input {
+        lines(input).map(Game::parse).collect()
+    }
+
+    fn part1(games: &Self::Input) -> impl Display {
+        let max = Cubes {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        games
+            .iter()
+            .filter(|g| g.is_possible(&max))
+            .map(|g| g.id)
+            .sum::<u32>()
+    }
+
+    fn part2(games: &Self::Input) -> impl Display {
+        games
+            .iter()
+            .map(Game::max_cubes)
+            .map(|c| c.power())
+            .sum::<u32>()
+    }
+}
+
+lib::register!(Day02);
+
+#[cfg(test)]
+mod test {
+    lib::example_tests!(example);
+}
@@ -0,0 +1,145 @@
+use core::fmt;
+use lib::*;
+use std::{collections::HashMap, fmt::Display};
+
+#[derive(Debug)]
+pub struct Number {
+    pub number: u32,
+    pub start_point: Pos,
+    pub end_point: Pos,
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} [{}, {}]",
+            self.number, self.start_point, self.end_point
+        )
+    }
+}
+
+pub fn is_serial_number(grid: &[Vec<char>], number: &Number) -> Option<(char, Pos)> {
+    for x in number.start_point.x - 1..=number.end_point.x + 1 {
+        let y = number.start_point.y - 1;
+        if y < 0 || y >= grid.len() as isize || x < 0 || x >= grid[0].len() as isize {
+            continue;
+        }
+        let c = grid[y as usize][x as usize];
+        if !c.is_ascii_digit() && c != '.' {
+            return Some((c, (x, y).into()));
+        }
+    }
+
+    for x in number.start_point.x - 1..=number.end_point.x + 1 {
+        let y = number.start_point.y + 1;
+        if y < 0 || y >= grid.len() as isize || x < 0 || x >= grid[0].len() as isize {
+            continue;
+        }
+        let c = grid[y as usize][x as usize];
+        if !c.is_ascii_digit() && c != '.' {
+            return Some((c, (x, y).into()));
+        }
+    }
+
+    for y in number.start_point.y - 1..=number.start_point.y + 1 {
+        let x = number.start_point.x - 1;
+        if y < 0 || y >= grid.len() as isize || x < 0 || x >= grid[0].len() as isize {
+            continue;
+        }
+        let c = grid[y as usize][x as usize];
+        if !c.is_ascii_digit() && c != '.' {
+            return Some((c, (x, y).into()));
+        }
+    }
+
+    for y in number.end_point.y - 1..=number.end_point.y + 1 {
+        let x = number.end_point.x + 1;
+        if y < 0 || y >= grid.len() as isize || x < 0 || x >= grid[0].len() as isize {
+            continue;
+        }
+        let c = grid[y as usize][x as usize];
+        if !c.is_ascii_digit() && c != '.' {
+            return Some((c, (x, y).into()));
+        }
+    }
+
+    None
+}
+
+pub struct Schematic {
+    pub grid: Vec<Vec<char>>,
+    pub numbers: Vec<Number>,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Self::Input {
+        let grid: Vec<Vec<_>> = lines(input).map(|line| line.chars().collect()).collect();
+
+        let mut numbers = vec![];
+        let mut buff = String::new();
+        let mut start_pos = None;
+        let mut last_pos = Pos::default();
+        for (y, line) in grid.iter().enumerate() {
+            for (x, &char) in line.iter().enumerate() {
+                if char.is_ascii_digit() {
+                    buff.push(char);
+                    if start_pos.is_none() {
+                        start_pos = Some((x as isize, y as isize).into())
+                    }
+                } else if start_pos.is_some() {
+                    numbers.push(Number {
+                        number: buff.parse().unwrap(),
+                        start_point: start_pos.unwrap(),
+                        end_point: last_pos,
+                    });
+                    buff.clear();
+                    start_pos = None;
+                }
+                last_pos = (x as isize, y as isize).into();
+            }
+        }
+
+        Schematic { grid, numbers }
+    }
+
+    fn part1(schematic: &Self::Input) -> impl Display {
+        schematic
+            .numbers
+            .iter()
+            .filter(|n| is_serial_number(&schematic.grid, n).is_some())
+            .map(|r| r.number)
+            .sum::<u32>()
+    }
+
+    fn part2(schematic: &Self::Input) -> impl Display {
+        let gears: Vec<_> = schematic
+            .numbers
+            .iter()
+            .filter_map(|n| is_serial_number(&schematic.grid, n).map(|ident| (n, ident)))
+            .filter(|(_, (c, _))| *c == '*')
+            .collect();
+
+        let mut map: HashMap<Pos, Vec<&Number>> = HashMap::new();
+        for (number, (_, pos)) in gears {
+            let v = map.entry(pos).or_default();
+            v.push(number);
+        }
+
+        map.values()
+            .filter(|v| v.len() == 2)
+            .map(|v| v.iter().map(|n| n.number).product::<u32>())
+            .sum::<u32>()
+    }
+}
+
+lib::register!(Day03);
+
+#[cfg(test)]
+mod test {
+    lib::example_tests!(example);
+}
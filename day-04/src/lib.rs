@@ -0,0 +1,71 @@
+use lib::*;
+use std::fmt::Display;
+
+pub struct Card {
+    pub win: Vec<usize>,
+    pub hand: Vec<usize>,
+}
+
+pub fn parse_numbers(raw: &str) -> Vec<usize> {
+    raw.split_ascii_whitespace()
+        .map(|v| v.parse().unwrap())
+        .collect()
+}
+
+impl Card {
+    pub fn parse(line: &str) -> Self {
+        let (_, line) = line.split_once(':').unwrap();
+
+        let (win_nums, hand_nums) = line.split_once('|').unwrap();
+        let win = parse_numbers(win_nums);
+        let hand = parse_numbers(hand_nums);
+
+        Self { win, hand }
+    }
+
+    pub fn wins(&self) -> usize {
+        self.hand.iter().filter(|v| self.win.contains(v)).count()
+    }
+
+    pub fn points(&self) -> usize {
+        match self.wins() {
+            0 => 0,
+            x => (2usize).pow(x as u32 - 1),
+        }
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Self::Input {
+        lines(input).map(Card::parse).collect()
+    }
+
+    fn part1(cards: &Self::Input) -> impl Display {
+        cards.iter().map(|c| c.points()).sum::<usize>()
+    }
+
+    fn part2(cards: &Self::Input) -> impl Display {
+        let mut stacks = vec![1usize; cards.len()];
+
+        for idx in 0..stacks.len() {
+            let card = &cards[idx];
+            let wins = card.wins();
+            for i in idx + 1..idx + 1 + wins {
+                stacks[i] += stacks[idx];
+            }
+        }
+
+        stacks.iter().sum::<usize>()
+    }
+}
+
+lib::register!(Day04);
+
+#[cfg(test)]
+mod test {
+    lib::example_tests!(example);
+}
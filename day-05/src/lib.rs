@@ -0,0 +1,184 @@
+use lib::*;
+use std::{fmt::Display, ops::Range};
+
+pub struct Map {
+    pub src: Range<isize>,
+    pub offset: isize,
+}
+
+impl Map {
+    pub fn parse(raw: &str) -> Self {
+        let mut split = raw.split_ascii_whitespace();
+        let dest: isize = split.next().unwrap().parse().unwrap();
+        let start: isize = split.next().unwrap().parse().unwrap();
+        let len: isize = split.next().unwrap().parse().unwrap();
+        Self {
+            src: start..start + len,
+            offset: dest - start,
+        }
+    }
+}
+
+pub struct Maps(pub Vec<Map>);
+
+impl Maps {
+    pub fn parse(raw: &str) -> Self {
+        Self(lines(raw).skip(1).map(Map::parse).collect())
+    }
+
+    pub fn map(&self, v: isize) -> isize {
+        self.0
+            .iter()
+            .find(|m| m.src.contains(&v))
+            .map(|m| v + m.offset)
+            .unwrap_or(v)
+    }
+
+    pub fn map_ranges(&self, ranges: &[Range<isize>]) -> Vec<Range<isize>> {
+        let mut ranges = ranges.to_vec();
+
+        let mut mapped = vec![];
+
+        while let Some(range) = ranges.pop() {
+            let mut intersection_found = false;
+
+            for m in &self.0 {
+                if let Some(intersect) = range_intersection(&m.src, &range) {
+                    mapped.push(intersect.start + m.offset..intersect.end + m.offset);
+
+                    let before = range.start..intersect.start;
+                    if !before.is_empty() {
+                        ranges.push(before);
+                    }
+
+                    let after = intersect.end..range.end;
+                    if !after.is_empty() {
+                        ranges.push(after);
+                    }
+
+                    intersection_found = true;
+                    break;
+                }
+            }
+
+            if !intersection_found {
+                mapped.push(range);
+            }
+        }
+
+        mapped
+    }
+}
+
+pub fn range_intersection(a: &Range<isize>, b: &Range<isize>) -> Option<Range<isize>> {
+    let start = a.start.max(b.start);
+    let end = a.end.min(b.end);
+
+    if start >= end {
+        None
+    } else {
+        Some(start..end)
+    }
+}
+
+pub struct Almanac {
+    pub seeds: Vec<isize>,
+    pub maps: Vec<Maps>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Self::Input {
+        let parts: Vec<_> = input.split("\n\n").collect();
+
+        let seeds: Vec<isize> = parts[0][7..]
+            .split_ascii_whitespace()
+            .map(|v| v.parse().unwrap())
+            .collect();
+
+        let maps: Vec<Maps> = parts[1..].iter().map(|v| Maps::parse(v)).collect();
+
+        Almanac { seeds, maps }
+    }
+
+    fn part1(Almanac { seeds, maps }: &Self::Input) -> impl Display {
+        seeds
+            .iter()
+            .map(|s| maps.iter().fold(*s, |c, m| m.map(c)))
+            .min()
+            .unwrap()
+    }
+
+    fn part2(Almanac { seeds, maps }: &Self::Input) -> impl Display {
+        let seeds: Vec<_> = seeds.chunks(2).map(|c| c[0]..c[0] + c[1]).collect();
+
+        maps.iter()
+            .fold(seeds, |s, m| m.map_ranges(&s))
+            .iter()
+            .map(|v| v.clone().start)
+            .min()
+            .unwrap()
+    }
+}
+
+lib::register!(Day05);
+
+// Maps every single seed of the ranges instead of whole ranges, which is
+// way too slow for the real input but obviously right.
+pub fn lowest_location_naive(Almanac { seeds, maps }: &Almanac) -> isize {
+    seeds
+        .chunks(2)
+        .flat_map(|c| c[0]..c[0] + c[1])
+        .map(|s| maps.iter().fold(s, |c, m| m.map(c)))
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    lib::example_tests!(example);
+
+    #[test]
+    fn test_range_intersection() {
+        assert_eq!(Some(2..4), range_intersection(&(1..4), &(2..6)));
+        assert_eq!(Some(2..4), range_intersection(&(1..4), &(2..4)));
+        assert_eq!(Some(2..3), range_intersection(&(1..4), &(2..3)));
+        assert_eq!(Some(1..2), range_intersection(&(1..4), &(0..2)));
+        assert_eq!(None, range_intersection(&(1..2), &(2..4)));
+    }
+
+    #[test]
+    fn test_map_ranges() {
+        let seeds = vec_of((int(0..40), int(1..10)), 1..4);
+        let map = (int(0..50), int(0..40), int(1..15));
+        let maps = vec_of(vec_of(map, 0..4), 1..4);
+
+        check(3000, (seeds, maps), |(seeds, maps)| {
+            let almanac = Almanac {
+                seeds: seeds
+                    .iter()
+                    .flat_map(|&(start, len)| [start as isize, len as isize])
+                    .collect(),
+                maps: maps
+                    .iter()
+                    .map(|m| {
+                        Maps(
+                            m.iter()
+                                .map(|&(dest, start, len)| {
+                                    Map::parse(&format!("{dest} {start} {len}"))
+                                })
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            };
+            let fast = Day05::part2(&almanac).to_string();
+            equal(fast, lowest_location_naive(&almanac).to_string())
+        });
+    }
+}
@@ -0,0 +1,133 @@
+use lib::*;
+use std::fmt::Display;
+
+pub struct Races {
+    pub time_distances: Vec<(isize, isize)>,
+    pub time: isize,
+    pub distance: isize,
+}
+
+impl Races {
+    pub fn margin(&self, possibilities: fn(isize, isize) -> isize) -> isize {
+        self.time_distances
+            .iter()
+            .map(|&(t, d)| possibilities(t, d))
+            .product()
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+
+    fn parse(input: &str) -> Self::Input {
+        let values: Vec<Vec<isize>> = lines(input)
+            .map(|line| {
+                line.split_ascii_whitespace()
+                    .skip(1)
+                    .map(|v| v.parse().unwrap())
+                    .collect()
+            })
+            .collect();
+
+        let time_distances = values[0]
+            .iter()
+            .cloned()
+            .zip(values[1].iter().cloned())
+            .collect();
+
+        let mut kerned = lines(input).map(|line| line[9..].replace(' ', "").parse().unwrap());
+
+        let time = kerned.next().unwrap();
+        let distance = kerned.next().unwrap();
+
+        Races {
+            time_distances,
+            time,
+            distance,
+        }
+    }
+
+    fn part1(races: &Self::Input) -> impl Display {
+        races.margin(possibilities)
+    }
+
+    fn part2(races: &Self::Input) -> impl Display {
+        possibilities_faster(races.time as f64, races.distance as f64)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant::new("imperative", Part::One, |races| {
+                races.margin(possibilities_imperative)
+            }),
+            Variant::new("quadratic", Part::One, |races| {
+                races.margin(|t, d| possibilities_faster(t as f64, d as f64))
+            }),
+            Variant::new("iter", Part::Two, |races| {
+                possibilities(races.time, races.distance)
+            }),
+            Variant::new("imperative", Part::Two, |races| {
+                possibilities_imperative(races.time, races.distance)
+            }),
+        ]
+    }
+}
+
+lib::register!(Day06);
+
+// This was my original approach, but i wanted to explore how to solve this
+// by using a quadratic equation.
+pub fn possibilities(time: isize, distance: isize) -> isize {
+    (1..time)
+        .filter(|charge| charge * (time - charge) > distance)
+        .count() as isize
+}
+
+// This is the imperative version of the code above which
+// - for some reason - runs signifficantly faster (~3x) than
+// the declarative version above. Probably, this is because
+// zero cost abstractions might not be applied in debug mode.
+// See benchmarks.txt for more details.
+pub fn possibilities_imperative(time: isize, distance: isize) -> isize {
+    let mut poss = 0;
+    for charge in 1..time {
+        if charge * (time - charge) > distance {
+            poss += 1;
+        }
+    }
+    poss
+}
+
+pub fn possibilities_faster(t: f64, d: f64) -> isize {
+    let h1 = 0.5 * (t - (t.powi(2) - 4.0 * d).sqrt());
+    let h2 = 0.5 * (t + (t.powi(2) - 4.0 * d).sqrt());
+    debug!("charge times between {h1} and {h2} beat {d} in {t}");
+
+    // Charging exactly until a root only ties the record, so the roots
+    // themselves must be excluded when they are integers. Without roots,
+    // or with a single one, the record cannot be beaten at all.
+    ((h2.ceil() - h1.floor()) as isize - 1).max(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    lib::example_tests!(example);
+
+    #[test]
+    fn test_possibilities() {
+        check(5000, (int(1..1000), int(0..1_000_000)), |&(time, d)| {
+            let time = time as isize;
+            // Up to twice the farthest distance, so that half of the races
+            // cannot be won.
+            let max = (time / 2) * (time - time / 2);
+            let distance = d as isize % (2 * max + 1);
+            let naive = possibilities(time, distance);
+            equal(possibilities_imperative(time, distance), naive)?;
+            equal(possibilities_faster(time as f64, distance as f64), naive)
+        });
+    }
+}
@@ -0,0 +1,104 @@
+use lib::*;
+use num::Integer;
+use std::{collections::HashMap, fmt::Display};
+
+pub type Mapping = HashMap<String, (String, String)>;
+
+pub fn parse_mapping(line: &str) -> (String, (String, String)) {
+    let (from, to) = line.split_once(" = ").unwrap();
+    let (left, right) = to[1..to.len() - 1].split_once(", ").unwrap();
+    (from.into(), (left.into(), right.into()))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (String, Mapping);
+
+    fn parse(input: &str) -> Self::Input {
+        let (dirs, mappings) = input.split_once("\n\n").unwrap();
+
+        let map = lines(mappings).map(parse_mapping).collect();
+
+        (dirs.into(), map)
+    }
+
+    fn part1((dirs, map): &Self::Input) -> impl Display {
+        let mut current = "AAA";
+        let mut count = 0;
+
+        'outer: loop {
+            for d in dirs.chars() {
+                if current == "ZZZ" {
+                    break 'outer;
+                }
+
+                current = match d {
+                    'L' => &map[current].0,
+                    'R' => &map[current].1,
+                    _ => panic!("invalid direction!"),
+                };
+
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    fn part2((dirs, map): &Self::Input) -> impl Display {
+        let starting_points: Vec<_> = map
+            .iter()
+            .filter(|(from, _)| from.chars().nth(2) == Some('A'))
+            .map(|(from, _)| from.as_str())
+            .collect();
+
+        let mut all_counts = vec![];
+
+        for sp in starting_points {
+            let mut count = 0usize;
+            let mut current = sp;
+            let mut first = None;
+            let mut counts = vec![];
+
+            let mut dirs = dirs.chars().cycle();
+            let mut d: char = dirs.next().unwrap();
+
+            'outer: loop {
+                while count == 0 || current.chars().nth(2) != Some('Z') {
+                    count += 1;
+                    current = match d {
+                        'L' => &map[current].0,
+                        'R' => &map[current].1,
+                        _ => panic!("invalid direction!"),
+                    };
+                    d = dirs.next().unwrap();
+                }
+
+                counts.push(count);
+
+                match first {
+                    None => {
+                        first = Some(current);
+                        count = 0;
+                    }
+                    Some(first) if current == first => break 'outer,
+                    _ => {}
+                }
+            }
+
+            all_counts.push(counts[0]);
+        }
+
+        let mut iter = all_counts.iter();
+        let lcm = iter.next().unwrap();
+        iter.fold(*lcm, |g, c| g.lcm(c))
+    }
+}
+
+lib::register!(Day08);
+
+#[cfg(test)]
+mod test {
+    lib::example_tests!(example, example_2);
+}
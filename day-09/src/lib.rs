@@ -0,0 +1,79 @@
+use lib::*;
+use std::fmt::Display;
+
+pub fn diff(v: &[isize]) -> Vec<isize> {
+    v.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+pub fn diffs(v: &[isize]) -> Vec<Vec<isize>> {
+    let mut curr = v.to_vec();
+    let mut res = vec![curr.clone()];
+
+    loop {
+        curr = diff(&curr);
+        res.push(curr.clone());
+        if curr.iter().all(|&v| v == curr[0]) {
+            break;
+        }
+    }
+
+    res
+}
+
+pub fn extrapolate(v: &[Vec<isize>]) -> isize {
+    let mut iter = v.iter().rev();
+    let e = *iter.next().unwrap().last().unwrap();
+    iter.fold(e, |e, line| e + line.last().unwrap())
+}
+
+pub fn extrapolate_backwards(v: &[Vec<isize>]) -> isize {
+    let mut iter = v.iter().rev();
+    let e = *iter.next().unwrap().first().unwrap();
+    iter.fold(e, |e, line| line.first().unwrap() - e)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<isize>>;
+
+    fn parse(input: &str) -> Self::Input {
+        lines(input)
+            .map(|line| {
+                line.split_ascii_whitespace()
+                    .map(|v| v.parse().unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(histories: &Self::Input) -> impl Display {
+        histories
+            .iter()
+            .map(|v| diffs(v))
+            .map(|v| extrapolate(&v))
+            .sum::<isize>()
+    }
+
+    fn part2(histories: &Self::Input) -> impl Display {
+        histories
+            .iter()
+            .map(|v| diffs(v))
+            .map(|v| extrapolate_backwards(&v))
+            .sum::<isize>()
+    }
+}
+
+lib::register!(Day09);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    lib::example_tests!(example);
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff(&[1, 3, 6, 10]), vec![2, 3, 4]);
+    }
+}
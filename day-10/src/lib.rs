@@ -0,0 +1,415 @@
+#![feature(iter_intersperse)]
+
+use core::fmt;
+use lib::*;
+use std::{collections::HashSet, fmt::Display};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Tile {
+    H,
+    V,
+    NE,
+    NW,
+    SW,
+    SE,
+    Ground,
+    Start,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::H => write!(f, "-"),
+            Tile::V => write!(f, "|"),
+            Tile::NE => write!(f, "L"),
+            Tile::NW => write!(f, "J"),
+            Tile::SW => write!(f, "7"),
+            Tile::SE => write!(f, "F"),
+            Tile::Ground => write!(f, "."),
+            Tile::Start => write!(f, "S"),
+        }
+    }
+}
+
+impl Tile {
+    pub fn parse(c: char) -> Self {
+        match c {
+            '|' => Self::V,
+            '-' => Self::H,
+            'L' => Self::NE,
+            'J' => Self::NW,
+            '7' => Self::SW,
+            'F' => Self::SE,
+            '.' => Self::Ground,
+            'S' => Self::Start,
+            x => panic!("invalid tile: {x}"),
+        }
+    }
+
+    pub fn go_dirs(&self) -> Vec<Direction> {
+        match self {
+            Tile::H => vec![Direction::Left, Direction::Right],
+            Tile::V => vec![Direction::Up, Direction::Down],
+            Tile::NE => vec![Direction::Up, Direction::Right],
+            Tile::NW => vec![Direction::Up, Direction::Left],
+            Tile::SW => vec![Direction::Down, Direction::Left],
+            Tile::SE => vec![Direction::Down, Direction::Right],
+            Tile::Ground => vec![],
+            Tile::Start => vec![
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+            ],
+        }
+    }
+
+    pub fn receive_dirs(&self) -> Vec<Direction> {
+        self.go_dirs().iter().map(|d| d.reverse()).collect()
+    }
+}
+
+pub struct Grid(pub Vec<Vec<Tile>>);
+
+impl Grid {
+    pub fn parse(input: &str) -> Self {
+        Self(
+            lines(input)
+                .rev()
+                .map(|line| line.chars().map(Tile::parse).collect())
+                .collect(),
+        )
+    }
+
+    pub fn replace_non_connected(&self, inloop: &HashSet<Pos>) -> Self {
+        Self(
+            self.0
+                .iter()
+                .enumerate()
+                .map(|(y, line)| {
+                    line.iter()
+                        .enumerate()
+                        .map(|(x, t)| {
+                            if inloop.contains(&(x as isize, y as isize).into()) {
+                                *t
+                            } else {
+                                Tile::Ground
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    pub fn intersperse(&self) -> Self {
+        let newline: Vec<_> = (0..self.0[0].len()).map(|_| Tile::Ground).collect();
+
+        let rows: Vec<Vec<_>> = self
+            .0
+            .iter()
+            .cloned()
+            .intersperse_with(|| newline.clone())
+            .map(|line| {
+                line.iter()
+                    .cloned()
+                    .intersperse_with(|| Tile::Ground)
+                    .collect()
+            })
+            .collect();
+
+        let border: Vec<_> = (0..rows[0].len()).map(|_| Tile::Ground).collect();
+        let rows = [vec![border.clone()], rows, vec![border.clone()]].concat();
+        let rows: Vec<_> = rows
+            .iter()
+            .map(|c| [vec![Tile::Ground], c.clone(), vec![Tile::Ground]].concat())
+            .collect();
+
+        Self(rows)
+    }
+
+    pub fn find(&self, tile: Tile) -> Pos {
+        for (y, line) in self.0.iter().enumerate() {
+            for (x, t) in line.iter().enumerate() {
+                if t == &tile {
+                    return (x as isize, y as isize).into();
+                }
+            }
+        }
+
+        panic!("Tile not found!")
+    }
+
+    pub fn at(&self, pos: Pos) -> Tile {
+        self.0[pos.y as usize][pos.x as usize]
+    }
+
+    pub fn in_bounds(&self, pos: Pos) -> bool {
+        !pos.is_negative() && pos.y < self.0.len() as isize && pos.x < self.0[0].len() as isize
+    }
+
+    pub fn could_connect(&self, curr_pos: Pos, dir: Direction) -> bool {
+        let to_pos = curr_pos + dir.into();
+        if !self.in_bounds(to_pos) {
+            return false;
+        }
+
+        let dest = self.at(to_pos);
+        dest.receive_dirs().contains(&dir)
+    }
+
+    pub fn can_go(&self, curr_pos: Pos, dir: Direction) -> bool {
+        if !self.could_connect(curr_pos, dir) {
+            return false;
+        }
+
+        let curr = self.at(curr_pos);
+        curr.go_dirs().contains(&dir)
+    }
+
+    pub fn neightbor_positions(&self, pos: Pos) -> Vec<Pos> {
+        [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ]
+        .iter()
+        .map(|&d| pos + d.into())
+        .filter(|&p| self.in_bounds(p))
+        .collect()
+    }
+
+    pub fn neighbors(&self, pos: Pos) -> Vec<Pos> {
+        let curr = self.at(pos);
+        curr.go_dirs()
+            .iter()
+            .filter(|&&d| self.can_go(pos, d))
+            .map(|&d| pos + d.into())
+            .collect()
+    }
+
+    pub fn part1(&self) -> (usize, HashSet<Pos>) {
+        let start_pos = self.find(Tile::Start);
+
+        let mut queue = vec![start_pos];
+        let mut visited = HashSet::new();
+        let mut i = 0;
+        while let Some(next) = queue.pop() {
+            i += 1;
+            visited.insert(next);
+
+            let neighbors = self.neighbors(next);
+            if neighbors.iter().filter(|&p| visited.contains(p)).count() > 1 {
+                debug!("loop of {} tiles closed at {next:?}", visited.len());
+                return (i / 2, visited);
+            }
+
+            neighbors
+                .iter()
+                .filter(|v| !visited.contains(v))
+                .for_each(|&v| queue.insert(0, v));
+        }
+
+        panic!("no result found!");
+    }
+
+    pub fn part2(&self) -> usize {
+        let start_pos = (0isize, 0isize).into();
+
+        let mut queue: Vec<_> = vec![start_pos];
+        let mut visited = HashSet::new();
+        while let Some(next) = queue.pop() {
+            visited.insert(next);
+
+            for p in self.neightbor_positions(next) {
+                if visited.contains(&p) {
+                    continue;
+                }
+
+                let t = self.at(p);
+                if t != Tile::Ground {
+                    continue;
+                }
+
+                if self.could_connect(p, Direction::Left) && self.could_connect(p, Direction::Left)
+                    || self.could_connect(p, Direction::Up)
+                        && self.could_connect(p, Direction::Down)
+                {
+                    continue;
+                }
+
+                queue.push(p);
+            }
+        }
+
+        let outer = visited
+            .iter()
+            .filter(|p| p.x % 2 == 1 && p.y % 2 == 1)
+            .count();
+
+        let mut all = 0;
+        for (y, line) in self.0.iter().enumerate() {
+            if y % 2 == 0 {
+                continue;
+            }
+            for (x, t) in line.iter().enumerate() {
+                if x % 2 == 0 || t != &Tile::Ground {
+                    continue;
+                }
+                all += 1;
+            }
+        }
+
+        all - outer
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.0.iter().rev() {
+            for c in line {
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        let (p1, _) = grid.part1();
+        p1
+    }
+
+    fn part2(grid: &Self::Input) -> impl Display {
+        let (_, inloop) = grid.part1();
+        let grid = grid.replace_non_connected(&inloop).intersperse();
+        trace!("interspersed loop:\n{grid}");
+
+        grid.part2()
+    }
+
+    fn renders(grid: &Self::Input) -> Vec<Render> {
+        let (_, inloop) = grid.part1();
+        vec![Render::new("loop", grid.replace_non_connected(&inloop))]
+    }
+}
+
+impl Generator for Day10 {
+    const SIZES: &'static [usize] = &[35, 70, 140, 280];
+
+    /// A square field of the given side length, at least 5, with a single
+    /// loop running around a random tree of 3 tiles wide corridors, which
+    /// encloses the tiles along the middle of the corridors. All other
+    /// tiles are random pipes or ground.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(5);
+        let nodes = (size - 1) / 4;
+
+        // Nodes of the tree are 3x3 blocks at every fourth tile, which are
+        // joined by filling the gap between neighboring ones.
+        let mut region = vec![vec![false; size]; size];
+        let mut fill = |x: usize, y: usize, w: usize, h: usize| {
+            for row in &mut region[y..y + h] {
+                row[x..x + w].fill(true);
+            }
+        };
+
+        let start = (rng.range(0..nodes), rng.range(0..nodes));
+        fill(4 * start.0 + 1, 4 * start.1 + 1, 3, 3);
+        let mut tree = vec![start];
+        let len = rng.range(nodes * nodes / 2..nodes * nodes + 1).max(1);
+
+        while tree.len() < len {
+            let (x, y) = *rng.pick(&tree);
+            let (nx, ny) = match rng.range(0..4) {
+                0 => (x + 1, y),
+                1 => (x.wrapping_sub(1), y),
+                2 => (x, y + 1),
+                _ => (x, y.wrapping_sub(1)),
+            };
+            if nx >= nodes || ny >= nodes || tree.contains(&(nx, ny)) {
+                continue;
+            }
+
+            fill(4 * nx + 1, 4 * ny + 1, 3, 3);
+            match (nx != x, x.min(nx), y.min(ny)) {
+                (true, x, y) => fill(4 * x + 4, 4 * y + 1, 1, 3),
+                (false, x, y) => fill(4 * x + 1, 4 * y + 4, 3, 1),
+            }
+            tree.push((nx, ny));
+        }
+
+        // The loop consists of the tiles of the region next to the outside.
+        let on_loop = |x: usize, y: usize| {
+            region[y][x] && (y - 1..=y + 1).any(|y| (x - 1..=x + 1).any(|x| !region[y][x]))
+        };
+
+        let pipe = |x: usize, y: usize| {
+            let connected = [
+                on_loop(x, y - 1),
+                on_loop(x, y + 1),
+                on_loop(x - 1, y),
+                on_loop(x + 1, y),
+            ];
+            match connected {
+                [true, true, false, false] => '|',
+                [false, false, true, true] => '-',
+                [true, false, false, true] => 'L',
+                [true, false, true, false] => 'J',
+                [false, true, true, false] => '7',
+                [false, true, false, true] => 'F',
+                c => panic!("loop tile at {x},{y} is connected to {c:?}"),
+            }
+        };
+
+        let mut grid: Vec<Vec<_>> = (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| match (on_loop(x, y), rng.chance(0.5)) {
+                        (true, _) => pipe(x, y),
+                        (false, true) => '.',
+                        (false, false) => *rng.pick(&['|', '-', 'L', 'J', '7', 'F']),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let tiles: Vec<_> = (0..size)
+            .flat_map(|y| (0..size).map(move |x| (x, y)))
+            .filter(|&(x, y)| on_loop(x, y))
+            .collect();
+
+        // Pipes next to the start must not point to it unless on the loop.
+        let &(x, y) = rng.pick(&tiles);
+        grid[y][x] = 'S';
+        for (x, y) in [(x, y - 1), (x, y + 1), (x - 1, y), (x + 1, y)] {
+            if !on_loop(x, y) {
+                grid[y][x] = '.';
+            }
+        }
+
+        grid.iter()
+            .map(|row| row.iter().collect())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+lib::register!(Day10, generator = Day10);
+
+#[cfg(test)]
+mod test {
+    lib::example_tests!(example, example_2);
+    lib::generator_tests!();
+}
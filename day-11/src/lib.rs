@@ -0,0 +1,148 @@
+use lib::*;
+use std::{cell::RefCell, fmt::Display};
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Grid(pub Vec<Vec<char>>);
+
+impl Grid {
+    pub fn parse(input: &str) -> Self {
+        Self(lines(input).map(|c| c.chars().collect()).collect())
+    }
+
+    pub fn flip_cw(&self) -> Self {
+        Self(flip_grid(&self.0))
+    }
+
+    pub fn find_all_galaxies(&self) -> Vec<Pos> {
+        self.0
+            .iter()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.iter()
+                    .enumerate()
+                    .filter(|(_, &c)| c == '#')
+                    .map(move |(x, _)| (x as isize, y as isize).into())
+            })
+            .collect()
+    }
+
+    pub fn empty_rows(&self) -> Vec<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.iter().all(|&c| c == '.'))
+            .map(|(y, _)| y)
+            .collect()
+    }
+
+    pub fn empty_cols(&self) -> Vec<usize> {
+        self.flip_cw().empty_rows()
+    }
+
+    pub fn solve(&self, multiplier: usize) -> usize {
+        let add: usize = multiplier - 1;
+
+        let empty_rows = self.empty_rows();
+        let empty_cols = self.empty_cols();
+
+        let galaxies: Vec<_> = self
+            .find_all_galaxies()
+            .iter()
+            .cloned()
+            .map(RefCell::new)
+            .collect();
+
+        for (i, &row) in empty_rows.iter().enumerate() {
+            galaxies
+                .iter()
+                .filter(|p| p.borrow().y as usize > row + add * i)
+                .for_each(|p| {
+                    let pc = *p.borrow();
+                    *p.borrow_mut() = Pos {
+                        x: pc.x,
+                        y: pc.y + add as isize,
+                    }
+                });
+        }
+
+        for (i, &col) in empty_cols.iter().enumerate() {
+            galaxies
+                .iter()
+                .filter(|p| p.borrow().x as usize > col + add * i)
+                .for_each(|p| {
+                    let pc = *p.borrow();
+                    *p.borrow_mut() = Pos {
+                        x: pc.x + add as isize,
+                        y: pc.y,
+                    };
+                });
+        }
+
+        galaxies
+            .iter()
+            .flat_map(|g1| {
+                galaxies
+                    .iter()
+                    .map(move |g2| g1.borrow().manhattan_distance(*g2.borrow()))
+            })
+            .sum::<usize>()
+            / 2
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        grid.solve(2)
+    }
+
+    fn part2(grid: &Self::Input) -> impl Display {
+        grid.solve(1_000_000)
+    }
+}
+
+impl Generator for Day11 {
+    const SIZES: &'static [usize] = &[35, 70, 140, 280, 560];
+
+    /// A square image of the given side length with about 2% galaxies.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut grid = vec![vec!['.'; size]; size];
+        for row in grid.iter_mut() {
+            for c in row.iter_mut().filter(|_| rng.chance(0.02)) {
+                *c = '#';
+            }
+        }
+        for _ in 0..2 {
+            grid[rng.range(0..size)][rng.range(0..size)] = '#';
+        }
+
+        grid.iter()
+            .map(|row| row.iter().collect())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+lib::register!(Day11, generator = Day11);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    lib::example_tests!(example);
+    lib::generator_tests!();
+
+    #[test]
+    fn test_flip_cw() {
+        let g = Grid(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]);
+        let ex = Grid(vec![vec!['a', 'd'], vec!['b', 'e'], vec!['c', 'f']]);
+        assert_eq!(g.flip_cw(), ex);
+    }
+}
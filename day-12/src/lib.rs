@@ -0,0 +1,202 @@
+#![feature(iter_intersperse)]
+
+use lib::*;
+use std::{cell::RefCell, collections::HashMap, fmt::Display, hash::Hash};
+
+#[derive(Eq, PartialEq, Clone, Copy, Hash, Debug)]
+pub enum Type {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Type {
+    pub fn parse(c: char) -> Self {
+        match c {
+            '.' => Self::Operational,
+            '#' => Self::Damaged,
+            '?' => Self::Unknown,
+            x => panic!("invalid character: {x}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct MapLine {
+    pub map: Vec<Type>,
+    pub blocks: Vec<usize>,
+}
+
+impl MapLine {
+    pub fn parse(line: &str) -> Self {
+        let (map_raw, blocks_raw) = line.split_once(' ').unwrap();
+
+        let blocks = blocks_raw.split(',').map(|v| v.parse().unwrap()).collect();
+        let map = map_raw.chars().map(Type::parse).collect();
+
+        Self { map, blocks }
+    }
+
+    pub fn count(&self) -> usize {
+        type Cache<'a> = HashMap<(&'a [Type], &'a [usize]), usize>;
+        let cache = RefCell::new(Cache::new());
+
+        fn count<'a>(
+            line: &'a [Type],
+            blocks: &'a [usize],
+            cache: &'a RefCell<Cache<'a>>,
+        ) -> usize {
+            if let Some(res) = cache.borrow().get(&(line, blocks)) {
+                return *res;
+            }
+
+            if line.is_empty() {
+                return if blocks.is_empty() { 1 } else { 0 };
+            }
+
+            if blocks.is_empty() {
+                return if line.contains(&Type::Damaged) { 0 } else { 1 };
+            }
+
+            let spring = line[0];
+            let block_size = blocks[0];
+            let mut c = 0;
+
+            if spring == Type::Operational || spring == Type::Unknown {
+                c += count(&line[1..], blocks, cache);
+            }
+
+            if (spring == Type::Damaged || spring == Type::Unknown)
+                && line.len() >= block_size
+                && !line[..block_size].contains(&Type::Operational)
+                && (line.len() == block_size || line[block_size] != Type::Damaged)
+            {
+                if line.len() <= block_size {
+                    c += count(&[], &blocks[1..], cache)
+                } else {
+                    c += count(&line[block_size + 1..], &blocks[1..], cache);
+                }
+            }
+
+            cache.borrow_mut().insert((line, blocks), c);
+            c
+        }
+
+        count(&self.map, &self.blocks, &cache)
+    }
+
+    // Tries out every assignment of the unknown springs, which is only
+    // feasible for short lines but serves as a reference for the above.
+    pub fn count_naive(&self) -> usize {
+        let unknowns: Vec<_> = (0..self.map.len())
+            .filter(|&i| self.map[i] == Type::Unknown)
+            .collect();
+
+        (0..1usize << unknowns.len())
+            .filter(|assignment| {
+                let mut map = self.map.clone();
+                for (bit, &i) in unknowns.iter().enumerate() {
+                    map[i] = match assignment >> bit & 1 {
+                        1 => Type::Damaged,
+                        _ => Type::Operational,
+                    };
+                }
+
+                let blocks: Vec<_> = map
+                    .split(|&t| t == Type::Operational)
+                    .filter(|b| !b.is_empty())
+                    .map(|b| b.len())
+                    .collect();
+                blocks == self.blocks
+            })
+            .count()
+    }
+
+    pub fn unfold(&self, folds: usize) -> Self {
+        let map = (0..folds)
+            .map(|_| self.map.iter())
+            .intersperse([Type::Unknown].iter())
+            .flatten()
+            .cloned()
+            .collect();
+
+        let blocks = self.blocks.repeat(folds);
+
+        Self { map, blocks }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<MapLine>;
+
+    fn parse(input: &str) -> Self::Input {
+        lines(input).map(MapLine::parse).collect()
+    }
+
+    fn part1(maps: &Self::Input) -> impl Display {
+        maps.iter().map(|m| m.count()).sum::<usize>()
+    }
+
+    fn part2(maps: &Self::Input) -> impl Display {
+        maps.iter().map(|m| m.unfold(5).count()).sum::<usize>()
+    }
+}
+
+impl Generator for Day12 {
+    const SIZES: &'static [usize] = &[250, 500, 1000, 2000, 4000];
+
+    /// The given number of condition records of 6 to 20 springs each, taken
+    /// from a random arrangement so that there is at least one solution.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let mut springs: Vec<_> = (0..rng.range(6..21))
+                    .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                    .collect();
+                let len = springs.len();
+                springs[rng.range(0..len)] = '#';
+
+                let blocks = springs
+                    .split(|&c| c == '.')
+                    .filter(|b| !b.is_empty())
+                    .map(|b| b.len().to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                for c in springs.iter_mut().filter(|_| rng.chance(0.5)) {
+                    *c = '?';
+                }
+                format!("{} {blocks}", springs.iter().collect::<String>())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+lib::register!(Day12, generator = Day12);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    lib::example_tests!(example);
+    lib::generator_tests!();
+
+    #[test]
+    fn test_count() {
+        let types = [Type::Operational, Type::Damaged, Type::Unknown];
+        let map = vec_of(one_of(&types), 0..8);
+        let blocks = vec_of(int(1..5), 0..4);
+
+        check(3000, (map, blocks, int(1..3)), |(map, blocks, folds)| {
+            let line = MapLine {
+                map: map.clone(),
+                blocks: blocks.clone(),
+            }
+            .unfold(*folds);
+            equal(line.count(), line.count_naive())
+        });
+    }
+}
@@ -0,0 +1,81 @@
+use lib::*;
+use std::fmt::Display;
+
+pub struct Grid(pub Vec<Vec<char>>);
+
+impl Grid {
+    pub fn parse(block: &str) -> Self {
+        Self(lines(block).map(|l| l.chars().collect()).collect())
+    }
+
+    pub fn flip_cw(&self) -> Self {
+        Self(flip_grid(&self.0))
+    }
+
+    pub fn diff_mirror_lines(&self, idx: usize) -> usize {
+        (0..=idx)
+            .rev()
+            .zip(idx + 1..self.0.len())
+            .fold(0usize, |diff_sum, (i, j)| {
+                diff_sum + diff_lines(&self.0[i], &self.0[j])
+            })
+    }
+
+    pub fn find_mirror_horizontal(&self, diff: usize) -> Option<usize> {
+        let pivot = self.0.len() / 2 - 1;
+
+        for i in (0..=pivot).rev() {
+            if self.diff_mirror_lines(i) == diff {
+                return Some(i);
+            }
+            if self.diff_mirror_lines(pivot + i + 1) == diff {
+                return Some(pivot + i + 1);
+            }
+        }
+
+        None
+    }
+
+    pub fn find_mirror_vertical(&self, diff: usize) -> Option<usize> {
+        self.flip_cw().find_mirror_horizontal(diff)
+    }
+
+    pub fn mirror_sum(&self, diff: usize) -> usize {
+        let h = self
+            .find_mirror_horizontal(diff)
+            .map(|v| (v + 1) * 100)
+            .unwrap_or(0);
+        let v = self.find_mirror_vertical(diff).map(|v| v + 1).unwrap_or(0);
+
+        h + v
+    }
+}
+
+pub fn diff_lines(a: &[char], b: &[char]) -> usize {
+    a.iter().zip(b).filter(|(va, vb)| va != vb).count()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.split("\n\n").map(Grid::parse).collect()
+    }
+
+    fn part1(grids: &Self::Input) -> impl Display {
+        grids.iter().map(|g| g.mirror_sum(0)).sum::<usize>()
+    }
+
+    fn part2(grids: &Self::Input) -> impl Display {
+        grids.iter().map(|g| g.mirror_sum(1)).sum::<usize>()
+    }
+}
+
+lib::register!(Day13);
+
+#[cfg(test)]
+mod test {
+    lib::example_tests!(example);
+}
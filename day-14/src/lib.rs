@@ -0,0 +1,252 @@
+use core::fmt;
+use lib::*;
+use std::{collections::HashSet, fmt::Display};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid {
+    pub blocks: Vec<Pos>,
+    pub balls: Vec<Pos>,
+    pub size: usize,
+}
+
+impl Grid {
+    pub fn parse(input: &str) -> Self {
+        let lines = lines(input);
+
+        let size = lines.clone().count();
+
+        let blocks = lines
+            .clone()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c == '#')
+                    .map(move |(x, _)| (x as isize, y as isize).into())
+            })
+            .collect();
+
+        let balls = lines
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c == 'O')
+                    .map(move |(x, _)| (x as isize, y as isize).into())
+            })
+            .collect();
+
+        Self {
+            blocks,
+            balls,
+            size,
+        }
+    }
+
+    pub fn new_stone_pos(&self, pos: Pos, dir: Direction) -> Pos {
+        match dir {
+            Direction::Left => {
+                let end = self
+                    .blocks
+                    .iter()
+                    .rfind(|b| b.y == pos.y && b.x < pos.x)
+                    .map(|b| b.x + 1)
+                    .unwrap_or(0);
+
+                let in_way = self
+                    .balls
+                    .iter()
+                    .filter(|b| b.y == pos.y && b.x < pos.x && b.x >= end)
+                    .count();
+
+                Pos {
+                    y: pos.y,
+                    x: (end + in_way as isize),
+                }
+            }
+            Direction::Right => {
+                let end = self
+                    .blocks
+                    .iter()
+                    .find(|b| b.y == pos.y && b.x > pos.x)
+                    .map(|b| b.x - 1)
+                    .unwrap_or(self.size as isize - 1);
+
+                let in_way = self
+                    .balls
+                    .iter()
+                    .filter(|b| b.y == pos.y && b.x > pos.x && b.x <= end)
+                    .count();
+
+                Pos {
+                    y: pos.y,
+                    x: (end - in_way as isize),
+                }
+            }
+            Direction::Up => {
+                let end = self
+                    .blocks
+                    .iter()
+                    .rfind(|b| b.x == pos.x && b.y < pos.y)
+                    .map(|b| b.y + 1)
+                    .unwrap_or(0);
+
+                let in_way = self
+                    .balls
+                    .iter()
+                    .filter(|b| b.x == pos.x && b.y < pos.y && b.y >= end)
+                    .count();
+
+                Pos {
+                    y: (end + in_way as isize),
+                    x: pos.x,
+                }
+            }
+            Direction::Down => {
+                let end = self
+                    .blocks
+                    .iter()
+                    .find(|b| b.x == pos.x && b.y > pos.y)
+                    .map(|b| b.y - 1)
+                    .unwrap_or(self.size as isize - 1);
+
+                let in_way = self
+                    .balls
+                    .iter()
+                    .filter(|b| b.x == pos.x && b.y > pos.y && b.y <= end)
+                    .count();
+
+                Pos {
+                    y: (end - in_way as isize),
+                    x: pos.x,
+                }
+            }
+        }
+    }
+
+    pub fn move_stones(&mut self, dir: Direction) {
+        self.balls = self
+            .balls
+            .iter()
+            .map(|&b| self.new_stone_pos(b, dir))
+            .collect();
+        self.balls.sort();
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let p = (x as isize, y as isize).into();
+                if self.balls.contains(&p) {
+                    write!(f, "O")?;
+                } else if self.blocks.contains(&p) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        let mut grid = grid.clone();
+
+        grid.move_stones(Direction::Up);
+
+        grid.balls
+            .iter()
+            .map(|&p| grid.size - p.y as usize)
+            .sum::<usize>()
+    }
+
+    fn part2(grid: &Self::Input) -> impl Display {
+        let mut grid = grid.clone();
+
+        let mut seen = HashSet::new();
+        let mut grids = vec![grid.clone()];
+        let mut loop_start = 0;
+
+        loop {
+            loop_start += 1;
+            for d in [
+                Direction::Up,
+                Direction::Left,
+                Direction::Down,
+                Direction::Right,
+            ] {
+                grid.move_stones(d);
+            }
+            trace!("after {loop_start} spin cycles:\n{grid}");
+            if !seen.insert(grid.clone()) {
+                break;
+            }
+            grids.push(grid.clone());
+        }
+
+        let first = grids.iter().position(|g| g == &grid).unwrap();
+        debug!(
+            "spin cycles repeat every {} cycles after {first}",
+            loop_start - first
+        );
+
+        let grid = &grids[((1000000000 - first) % (loop_start - first)) + first];
+
+        grid.balls
+            .iter()
+            .map(|&p| grid.size - p.y as usize)
+            .sum::<usize>()
+    }
+
+    fn renders(grid: &Self::Input) -> Vec<Render> {
+        let mut tilted = grid.clone();
+        tilted.move_stones(Direction::Up);
+
+        vec![
+            Render::new("input", grid),
+            Render::new("tilted north", tilted),
+        ]
+    }
+}
+
+impl Generator for Day14 {
+    const SIZES: &'static [usize] = &[13, 25, 50, 100];
+
+    /// A square platform of the given side length with about 20% rounded
+    /// and 15% cube shaped rocks.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| match rng.range(0..100) {
+                        0..20 => 'O',
+                        20..35 => '#',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+lib::register!(Day14, generator = Day14);
+
+#[cfg(test)]
+mod test {
+    lib::example_tests!(example);
+    lib::generator_tests!();
+}
@@ -0,0 +1,120 @@
+use std::{cell::RefCell, collections::HashMap, fmt::Display};
+
+use lib::*;
+
+pub enum Op {
+    Remove { label: String },
+    Add { label: String, fl: usize },
+}
+
+impl Op {
+    pub fn parse(v: &str) -> Self {
+        if let Some(label) = v.strip_suffix('-') {
+            Self::Remove {
+                label: label.into(),
+            }
+        } else {
+            let (label, fl) = v.split_once('=').unwrap();
+            let fl = fl.parse().unwrap();
+            Self::Add {
+                label: label.into(),
+                fl,
+            }
+        }
+    }
+}
+
+pub struct Step {
+    pub raw: String,
+    pub op: Op,
+}
+
+impl Step {
+    pub fn parse(v: &str) -> Self {
+        Self {
+            raw: v.into(),
+            op: Op::parse(v),
+        }
+    }
+}
+
+pub fn hash(v: &str) -> usize {
+    let mut curr = 0;
+
+    for c in v.chars() {
+        curr += c as usize;
+        curr *= 17;
+        curr %= 256;
+    }
+
+    curr
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.split(',').map(Step::parse).collect()
+    }
+
+    fn part1(steps: &Self::Input) -> impl Display {
+        steps.iter().map(|s| hash(&s.raw)).sum::<usize>()
+    }
+
+    fn part2(steps: &Self::Input) -> impl Display {
+        let mut map = HashMap::new();
+
+        for step in steps {
+            match &step.op {
+                Op::Add { label, fl } => {
+                    let (label, fl) = (label.as_str(), *fl);
+                    let entry = map.entry(hash(label)).or_insert(RefCell::new(vec![]));
+                    let mut lenses = entry.borrow_mut();
+
+                    if let Some(i) = lenses.iter().position(|(lbl, _)| lbl == &label) {
+                        lenses.remove(i);
+                        lenses.insert(i, (label, fl));
+                    } else {
+                        lenses.push((label, fl));
+                    }
+                }
+                Op::Remove { label } => {
+                    let label = label.as_str();
+                    if let Some(mut lenses) = map.get(&hash(label)).map(|v| v.borrow_mut()) {
+                        if let Some(i) = lenses.iter().position(|(lbl, _)| lbl == &label) {
+                            lenses.remove(i);
+                        };
+                    };
+                }
+            }
+        }
+
+        map.iter()
+            .flat_map(|(&k, v)| {
+                v.borrow()
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, &lens)| (k, idx, lens))
+                    .collect::<Vec<_>>()
+            })
+            .map(|(k, idx, (_, fl))| (k + 1) * (idx + 1) * fl)
+            .sum::<usize>()
+    }
+}
+
+lib::register!(Day15);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    lib::example_tests!(example);
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash("rn"), 0);
+    }
+}
@@ -0,0 +1,172 @@
+use lib::*;
+use std::{cell::RefCell, collections::HashSet, fmt::Display};
+
+pub struct Grid(pub Vec<Vec<char>>);
+
+impl Grid {
+    pub fn parse(input: &str) -> Self {
+        Self(lines(input).map(|l| l.chars().collect()).collect())
+    }
+
+    pub fn in_bounds(&self, p: Pos) -> bool {
+        !p.is_negative() && p.y < self.0.len() as isize && p.x < self.0[0].len() as isize
+    }
+
+    pub fn mv(&self, p: Pos, dir: Direction, covered: &RefCell<HashSet<(Pos, Direction)>>) {
+        if !self.in_bounds(p) || covered.borrow().contains(&(p, dir)) {
+            return;
+        }
+
+        covered.borrow_mut().insert((p, dir));
+
+        match self.0[p.y as usize][p.x as usize] {
+            '.' => self.mv(p.mv(dir), dir, covered),
+            '-' => match dir {
+                Direction::Left | Direction::Right => self.mv(p.mv(dir), dir, covered),
+                Direction::Down | Direction::Up => {
+                    self.mv(p.mv(Direction::Left), Direction::Left, covered);
+                    self.mv(p.mv(Direction::Right), Direction::Right, covered);
+                }
+            },
+            '|' => match dir {
+                Direction::Down | Direction::Up => self.mv(p.mv(dir), dir, covered),
+                Direction::Left | Direction::Right => {
+                    self.mv(p.mv(Direction::Down), Direction::Down, covered);
+                    self.mv(p.mv(Direction::Up), Direction::Up, covered);
+                }
+            },
+            '/' => match dir {
+                Direction::Up => self.mv(p.mv(Direction::Left), Direction::Left, covered),
+                Direction::Down => self.mv(p.mv(Direction::Right), Direction::Right, covered),
+                Direction::Left => self.mv(p.mv(Direction::Up), Direction::Up, covered),
+                Direction::Right => self.mv(p.mv(Direction::Down), Direction::Down, covered),
+            },
+            '\\' => match dir {
+                Direction::Up => self.mv(p.mv(Direction::Right), Direction::Right, covered),
+                Direction::Down => self.mv(p.mv(Direction::Left), Direction::Left, covered),
+                Direction::Left => self.mv(p.mv(Direction::Down), Direction::Down, covered),
+                Direction::Right => self.mv(p.mv(Direction::Up), Direction::Up, covered),
+            },
+            c => panic!("invalid character: {c}"),
+        }
+    }
+
+    pub fn energized(&self, pos: Pos, dir: Direction) -> HashSet<Pos> {
+        let covered = RefCell::new(HashSet::new());
+        self.mv(pos, dir, &covered);
+        let covered = covered.borrow();
+        covered.iter().map(|&(p, _)| p).collect()
+    }
+
+    pub fn find_covered(&self, pos: Pos, dir: Direction) -> usize {
+        self.energized(pos, dir).len()
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        grid.find_covered((0isize, 0isize).into(), Direction::Right)
+    }
+
+    fn part2(grid: &Self::Input) -> impl Display {
+        (0..grid.0[0].len())
+            .map(|x| {
+                (
+                    Pos {
+                        x: x as isize,
+                        y: 0,
+                    },
+                    Direction::Up,
+                )
+            })
+            .chain((0..grid.0[0].len()).map(|x| {
+                (
+                    Pos {
+                        x: x as isize,
+                        y: grid.0.len() as isize - 1,
+                    },
+                    Direction::Down,
+                )
+            }))
+            .chain((0..grid.0.len()).map(|y| {
+                (
+                    Pos {
+                        x: 0,
+                        y: y as isize,
+                    },
+                    Direction::Right,
+                )
+            }))
+            .chain((0..grid.0.len()).map(|y| {
+                (
+                    Pos {
+                        x: grid.0[0].len() as isize - 1,
+                        y: y as isize,
+                    },
+                    Direction::Left,
+                )
+            }))
+            .map(|(p, d)| grid.find_covered(p, d))
+            .max()
+            .unwrap()
+    }
+
+    fn renders(grid: &Self::Input) -> Vec<Render> {
+        let energized = grid.energized((0isize, 0isize).into(), Direction::Right);
+        let text: String = grid
+            .0
+            .iter()
+            .enumerate()
+            .map(|(y, line)| {
+                let mut row: String = (0..line.len())
+                    .map(
+                        |x| match energized.contains(&(x as isize, y as isize).into()) {
+                            true => '#',
+                            false => '.',
+                        },
+                    )
+                    .collect();
+                row.push('\n');
+                row
+            })
+            .collect();
+
+        vec![Render::new("energized", text)]
+    }
+}
+
+impl Generator for Day16 {
+    const SIZES: &'static [usize] = &[14, 28, 55, 110];
+
+    /// A square contraption of the given side length with about 10%
+    /// mirrors and splitters.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| match rng.chance(0.1) {
+                        true => *rng.pick(&['/', '\\', '|', '-']),
+                        false => '.',
+                    })
+                    .collect()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+lib::register!(Day16, generator = Day16);
+
+#[cfg(test)]
+mod test {
+    lib::example_tests!(example);
+    lib::generator_tests!();
+}
@@ -0,0 +1,387 @@
+use lib::*;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::RangeInclusive,
+};
+
+#[derive(Clone, Debug)]
+pub struct Part {
+    pub ratings: HashMap<char, usize>,
+}
+
+impl Part {
+    pub fn parse(line: &str) -> Self {
+        let ratings = line[1..line.len() - 1]
+            .split(',')
+            .map(|r| (r.chars().next().unwrap(), r[2..].parse().unwrap()))
+            .collect();
+        Self { ratings }
+    }
+
+    pub fn value(&self) -> usize {
+        self.ratings.values().sum()
+    }
+}
+
+#[derive(Debug)]
+pub enum Filter {
+    Lower(usize),
+    Higher(usize),
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    pub field: char,
+    pub filter: Filter,
+    pub next: String,
+}
+
+impl Rule {
+    pub fn matches(&self, p: &Part) -> Option<&str> {
+        let &rating = p.ratings.get(&self.field)?;
+
+        let ok = match self.filter {
+            Filter::Higher(v) => rating > v,
+            Filter::Lower(v) => rating < v,
+        };
+
+        if ok {
+            Some(self.next.as_str())
+        } else {
+            None
+        }
+    }
+}
+
+impl Rule {
+    pub fn parse(v: &str) -> Self {
+        let mut chars = v.chars();
+        let field = chars.next().unwrap();
+
+        let (val, next) = v[2..].split_once(':').unwrap();
+
+        let filter = match chars.next().unwrap() {
+            '>' => Filter::Higher(val.parse().unwrap()),
+            '<' => Filter::Lower(val.parse().unwrap()),
+            x => panic!("invalid filter character: {x}"),
+        };
+
+        Self {
+            field,
+            filter,
+            next: next.into(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    pub rules: Vec<Rule>,
+    pub fallback: String,
+}
+
+impl Workflow {
+    pub fn parse(line: &str) -> (String, Self) {
+        let (key, rules) = line[..line.len() - 1].split_once('{').unwrap();
+
+        let split: Vec<_> = rules.split(',').collect();
+
+        let rules = split[..split.len() - 1]
+            .iter()
+            .map(|&r| Rule::parse(r))
+            .collect();
+
+        let fallback = split.last().unwrap().to_string();
+
+        let w = Self { rules, fallback };
+        (key.into(), w)
+    }
+
+    pub fn process(&self, part: &Part) -> &str {
+        for r in &self.rules {
+            if let Some(next) = r.matches(part) {
+                return next;
+            }
+        }
+
+        self.fallback.as_str()
+    }
+}
+
+pub fn count(
+    workflows: &HashMap<String, Workflow>,
+    ranges: &HashMap<char, RangeInclusive<usize>>,
+    curr: &str,
+) -> usize {
+    match curr {
+        "R" => 0,
+        "A" => ranges.values().map(|r| r.clone().count()).product(),
+        curr => {
+            let workflow = workflows.get(curr).unwrap();
+            let mut total = 0;
+
+            let mut current_ranges = ranges.clone();
+
+            for rule in &workflow.rules {
+                let (start, end) = current_ranges[&rule.field].clone().into_inner();
+                let (incl, excl) = match rule.filter {
+                    Filter::Lower(n) => (start..=(n - 1).min(end), n.max(start)..=end),
+                    Filter::Higher(n) => ((n + 1).max(start)..=end, start..=n.min(end)),
+                };
+
+                if !incl.is_empty() {
+                    let mut new_ranges = current_ranges.clone();
+                    new_ranges.insert(rule.field, incl);
+                    total += count(workflows, &new_ranges, &rule.next);
+                }
+
+                // Nothing is left for the following rules and the fallback
+                // once a rule took the whole range.
+                if excl.is_empty() {
+                    return total;
+                }
+
+                current_ranges.insert(rule.field, excl);
+            }
+
+            if current_ranges.values().all(|r| !r.is_empty()) {
+                total += count(workflows, &current_ranges, &workflow.fallback);
+            }
+
+            total
+        }
+    }
+}
+
+pub struct System {
+    pub workflows: HashMap<String, Workflow>,
+    pub parts: Vec<Part>,
+}
+
+impl System {
+    pub fn accepts(&self, part: &Part) -> bool {
+        let mut next = "in";
+        while next != "R" && next != "A" {
+            next = self.workflows.get(next).unwrap().process(part);
+        }
+        next == "A"
+    }
+
+    pub fn combinations(&self, ratings: RangeInclusive<usize>) -> usize {
+        let ranges = "xmas".chars().map(|c| (c, ratings.clone())).collect();
+        count(&self.workflows, &ranges, "in")
+    }
+
+    // Runs every single part with ratings in the range through the
+    // workflows, which is only feasible for tiny ranges.
+    pub fn combinations_naive(&self, ratings: RangeInclusive<usize>) -> usize {
+        let mut parts = vec![HashMap::new()];
+        for field in "xmas".chars() {
+            parts = parts
+                .into_iter()
+                .flat_map(|p| {
+                    ratings.clone().map(move |r| {
+                        let mut p = p.clone();
+                        p.insert(field, r);
+                        p
+                    })
+                })
+                .collect();
+        }
+
+        parts
+            .into_iter()
+            .filter(|ratings| {
+                self.accepts(&Part {
+                    ratings: ratings.clone(),
+                })
+            })
+            .count()
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+
+    fn parse(input: &str) -> Self::Input {
+        let (workflows, parts) = input.split_once("\n\n").unwrap();
+
+        let parts = lines(parts).map(Part::parse).collect();
+        let workflows = lines(workflows).map(Workflow::parse).collect();
+
+        System { workflows, parts }
+    }
+
+    fn part1(system: &Self::Input) -> impl Display {
+        system
+            .parts
+            .iter()
+            .filter(|p| system.accepts(p))
+            .map(|p| p.value())
+            .sum::<usize>()
+    }
+
+    fn part2(system: &Self::Input) -> impl Display {
+        system.combinations(1..=4000)
+    }
+}
+
+/// Random distinct names of lowercase letters, excluding `in`.
+fn names(n: usize, rng: &mut Rng) -> Vec<String> {
+    let mut names = HashSet::new();
+    while names.len() < n {
+        let name: String = (0..rng.range(2..4))
+            .map(|_| (b'a' + rng.range(0..26) as u8) as char)
+            .collect();
+        if name != "in" {
+            names.insert(name);
+        }
+    }
+
+    let mut names: Vec<_> = names.into_iter().collect();
+    names.sort();
+    rng.shuffle(&mut names);
+    names
+}
+
+impl Generator for Day19 {
+    const SIZES: &'static [usize] = &[50, 100, 200, 400, 800];
+
+    /// The given number of workflows and parts. Like in the real inputs,
+    /// the workflows form a tree starting at `in`, so that each of them is
+    /// referred to once. Every rule splits the ratings reaching it at a
+    /// random threshold, so that all workflows are reached by some ratings.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let mut names = names(size - 1, rng);
+        names.insert(0, "in".into());
+
+        let mut children = vec![vec![]; size];
+        for i in 1..size {
+            children[rng.range(0..i)].push(Some(i));
+        }
+
+        // Inclusive ranges of the xmas ratings reaching each workflow, which
+        // are known before it is generated as parents precede children.
+        let mut ranges = vec![[(1, 4000); 4]; size];
+        let mut workflows = vec![];
+
+        for (i, mut targets) in children.into_iter().enumerate() {
+            while targets.len() < 2 || rng.chance(0.3) {
+                targets.push(None);
+            }
+            rng.shuffle(&mut targets);
+
+            let mut remaining = ranges[i];
+            let mut target_name = |target: Option<usize>, reaching, rng: &mut Rng| match target {
+                Some(t) => {
+                    ranges[t] = reaching;
+                    names[t].clone()
+                }
+                None => rng.pick(&["A", "R"]).to_string(),
+            };
+
+            let fallback = targets.pop().unwrap();
+            let mut rules = vec![];
+            for target in targets {
+                let splittable: Vec<_> = (0..4)
+                    .filter(|&f| remaining[f].0 < remaining[f].1)
+                    .collect();
+                let Some(&field) = splittable.get(rng.range(0..splittable.len().max(1))) else {
+                    // Nothing is left to split, so the rule never matches.
+                    let name = target_name(target, remaining, rng);
+                    rules.push(format!("x<{}:{name}", remaining[0].0));
+                    continue;
+                };
+
+                let (lo, hi) = remaining[field];
+                let mut reaching = remaining;
+                let rule = match rng.chance(0.5) {
+                    true => {
+                        let v = rng.range(lo + 1..hi + 1);
+                        (reaching[field], remaining[field]) = ((lo, v - 1), (v, hi));
+                        format!("{}<{v}", b"xmas"[field] as char)
+                    }
+                    false => {
+                        let v = rng.range(lo..hi);
+                        (reaching[field], remaining[field]) = ((v + 1, hi), (lo, v));
+                        format!("{}>{v}", b"xmas"[field] as char)
+                    }
+                };
+                rules.push(format!("{rule}:{}", target_name(target, reaching, rng)));
+            }
+
+            let fallback = target_name(fallback, remaining, rng);
+            workflows.push(format!("{}{{{},{fallback}}}", names[i], rules.join(",")));
+        }
+
+        let parts: Vec<_> = (0..size)
+            .map(|_| {
+                let [x, m, a, s] = [(); 4].map(|_| rng.range(1..4001));
+                format!("{{x={x},m={m},a={a},s={s}}}")
+            })
+            .collect();
+
+        format!("{}\n\n{}", workflows.join("\n"), parts.join("\n"))
+    }
+}
+
+lib::register!(Day19, generator = Day19);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    lib::example_tests!(example);
+    lib::generator_tests!();
+
+    #[test]
+    fn test_combinations() {
+        const MAX: usize = 4;
+
+        let rule = (
+            one_of(&['x', 'm', 'a', 's']),
+            one_of(&['<', '>']),
+            int(1..MAX + 2),
+            int(0..5),
+        );
+        let workflow = (vec_of(rule, 0..4), int(0..5));
+
+        check(2000, vec_of(workflow, 1..5), |workflows| {
+            let name = |i: usize| match i {
+                0 => "in".to_string(),
+                i => format!("w{i}"),
+            };
+            // Workflows only refer to later ones, so that there are no cycles.
+            let target = |i: usize, t: usize| match t {
+                0 | 1 => ["A", "R"][t].to_string(),
+                t if i + t - 1 < workflows.len() => name(i + t - 1),
+                t => ["A", "R"][t % 2].to_string(),
+            };
+
+            let workflows = workflows
+                .iter()
+                .enumerate()
+                .map(|(i, (rules, fallback))| {
+                    let rules: String = rules
+                        .iter()
+                        .map(|&(field, op, v, t)| format!("{field}{op}{v}:{},", target(i, t)))
+                        .collect();
+                    Workflow::parse(&format!("{}{{{rules}{}}}", name(i), target(i, *fallback)))
+                })
+                .collect();
+
+            let system = System {
+                workflows,
+                parts: vec![],
+            };
+            equal(
+                system.combinations(1..=MAX),
+                system.combinations_naive(1..=MAX),
+            )
+        });
+    }
+}
@@ -0,0 +1,292 @@
+use lib::*;
+use num::Integer;
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Pulse {
+    High,
+    Low,
+}
+
+#[derive(Clone)]
+pub struct Broadcaster {
+    pub outputs: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct FlipFlop {
+    pub on: bool,
+    pub outputs: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct Conjunction {
+    pub states: HashMap<String, Pulse>,
+    pub outputs: Vec<String>,
+}
+
+pub trait Module {
+    fn receive(&mut self, from: &str, pulse: Pulse) -> Option<Pulse>;
+    fn outputs(&self) -> Vec<String>;
+    fn clone_box(&self) -> Box<dyn Module>;
+    fn set_inputs(&mut self, _inputs: &[String]) {}
+    fn is_conjunction(&self) -> bool {
+        false
+    }
+}
+
+impl Module for Broadcaster {
+    fn receive(&mut self, _: &str, pulse: Pulse) -> Option<Pulse> {
+        Some(pulse)
+    }
+
+    fn clone_box(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
+    }
+
+    fn outputs(&self) -> Vec<String> {
+        self.outputs.to_vec()
+    }
+}
+
+impl Module for FlipFlop {
+    fn receive(&mut self, _: &str, pulse: Pulse) -> Option<Pulse> {
+        match pulse {
+            Pulse::High => None,
+            Pulse::Low => {
+                self.on = !self.on;
+                Some(if self.on { Pulse::High } else { Pulse::Low })
+            }
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
+    }
+
+    fn outputs(&self) -> Vec<String> {
+        self.outputs.to_vec()
+    }
+}
+
+impl Module for Conjunction {
+    fn receive(&mut self, from: &str, pulse: Pulse) -> Option<Pulse> {
+        self.states.insert(from.to_string(), pulse);
+        if self.states.values().all(|v| v == &Pulse::High) {
+            Some(Pulse::Low)
+        } else {
+            Some(Pulse::High)
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
+    }
+
+    fn outputs(&self) -> Vec<String> {
+        self.outputs.to_vec()
+    }
+
+    fn set_inputs(&mut self, inputs: &[String]) {
+        for i in inputs {
+            self.states.insert(i.clone(), Pulse::Low);
+        }
+    }
+
+    fn is_conjunction(&self) -> bool {
+        true
+    }
+}
+
+pub fn parse_module(line: &str) -> (String, Box<dyn Module>) {
+    let (ident, outputs) = line.split_once(" -> ").unwrap();
+
+    let outputs = outputs.split(", ").map(|s| s.to_string()).collect();
+
+    match ident.chars().next().unwrap() {
+        'b' => (ident.to_string(), Box::new(Broadcaster { outputs })),
+        '%' => (
+            ident[1..].to_string(),
+            Box::new(FlipFlop { outputs, on: false }),
+        ),
+        '&' => (
+            ident[1..].to_string(),
+            Box::new(Conjunction {
+                outputs,
+                states: HashMap::new(),
+            }),
+        ),
+        i => panic!("unexpected ident: {i}"),
+    }
+}
+
+pub struct Network {
+    modules: HashMap<String, RefCell<Box<dyn Module>>>,
+}
+
+impl Network {
+    /// Builds a network in its initial state from the parsed modules.
+    pub fn new(modules: &[(String, Box<dyn Module>)]) -> Self {
+        let modules: HashMap<_, _> = modules
+            .iter()
+            .map(|(k, v)| (k.clone(), RefCell::new(v.clone_box())))
+            .collect();
+
+        for (k, v) in modules.iter().filter(|(_, v)| v.borrow().is_conjunction()) {
+            let inputs: Vec<_> = modules
+                .iter()
+                .filter(|(_, v)| v.borrow().outputs().contains(k))
+                .map(|(s, _)| s.to_string())
+                .collect();
+            v.borrow_mut().set_inputs(&inputs);
+        }
+
+        Self { modules }
+    }
+
+    /// Names of the modules sending pulses to the given module.
+    pub fn inputs(&self, name: &str) -> Vec<&str> {
+        self.modules
+            .iter()
+            .filter(|(_, v)| v.borrow().outputs().iter().any(|o| o == name))
+            .map(|(k, _)| k.as_str())
+            .collect()
+    }
+
+    /// Sends a low pulse to the broadcaster and processes all pulses caused
+    /// by it in order, calling `on_pulse` with the sender, receiver and
+    /// pulse of each of them.
+    pub fn push_button(&self, mut on_pulse: impl FnMut(&str, &str, Pulse)) {
+        let mut queue = vec![("button".to_string(), "broadcaster".to_string(), Pulse::Low)];
+
+        while let Some((from, to, pulse)) = queue.pop() {
+            on_pulse(&from, &to, pulse);
+
+            let Some(next) = self.modules.get(&to) else {
+                continue;
+            };
+
+            let res = { next.borrow_mut().receive(&from, pulse) };
+            if let Some(res) = res {
+                let outs = { next.borrow().outputs() };
+                for o in outs {
+                    queue.insert(0, (to.clone(), o, res));
+                }
+            }
+        }
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<(String, Box<dyn Module>)>;
+
+    fn parse(input: &str) -> Self::Input {
+        lines(input).map(parse_module).collect()
+    }
+
+    fn part1(modules: &Self::Input) -> impl Display {
+        let network = Network::new(modules);
+
+        let mut low = 0;
+        let mut high = 0;
+
+        for _ in 0..1000 {
+            network.push_button(|_, _, pulse| match pulse {
+                Pulse::High => high += 1,
+                Pulse::Low => low += 1,
+            });
+        }
+
+        low * high
+    }
+
+    fn part2(modules: &Self::Input) -> impl Display {
+        let network = Network::new(modules);
+
+        let feed = network.inputs("rx")[0];
+
+        let mut cycles: HashMap<String, usize> = HashMap::new();
+        let senders = network.inputs(feed).len();
+
+        for i in 1..usize::MAX {
+            network.push_button(|from, to, pulse| {
+                if to == feed && pulse == Pulse::High {
+                    cycles.entry(from.to_string()).or_insert(i);
+                }
+            });
+
+            if cycles.len() == senders {
+                return cycles.values().fold(1usize, |g, c| g.lcm(c));
+            }
+        }
+
+        panic!("no result found!");
+    }
+}
+
+impl Generator for Day20 {
+    const SIZES: &'static [usize] = &[1, 2, 4, 8, 16];
+
+    /// A network of the given number of 12 bit counters like in the real
+    /// inputs. Each counter is a chain of flip-flops whose conjunction
+    /// resets it once it reaches a random odd number of at least 2048, and
+    /// sends a low pulse to `rx` through an inverter and a conjunction of
+    /// all of them.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+
+        let mut names = HashSet::new();
+        while names.len() < 14 * size + 1 {
+            let name: String = (0..3)
+                .map(|_| (b'a' + rng.range(0..26) as u8) as char)
+                .collect();
+            names.insert(name);
+        }
+        let mut names: Vec<_> = names.into_iter().collect();
+        names.sort();
+        rng.shuffle(&mut names);
+
+        let output = names.pop().unwrap();
+        let mut modules = vec![format!("&{output} -> rx")];
+        let mut starts = vec![];
+
+        for _ in 0..size {
+            let bits: Vec<_> = names.drain(..12).collect();
+            let (conjunction, inverter) = (names.pop().unwrap(), names.pop().unwrap());
+            let cycle = rng.range(2048..4096) | 1;
+
+            let mut resets = vec![bits[0].clone()];
+            for (i, bit) in bits.iter().enumerate() {
+                let mut outputs: Vec<_> = bits.get(i + 1).into_iter().cloned().collect();
+                match cycle & (1 << i) != 0 {
+                    true => outputs.push(conjunction.clone()),
+                    false => resets.push(bit.clone()),
+                }
+                modules.push(format!("%{bit} -> {}", outputs.join(", ")));
+            }
+            resets.push(inverter.clone());
+
+            modules.push(format!("&{conjunction} -> {}", resets.join(", ")));
+            modules.push(format!("&{inverter} -> {output}"));
+            starts.push(bits[0].clone());
+        }
+
+        rng.shuffle(&mut modules);
+        modules.insert(0, format!("broadcaster -> {}", starts.join(", ")));
+        modules.join("\n")
+    }
+}
+
+lib::register!(Day20, generator = Day20);
+
+#[cfg(test)]
+mod test {
+    lib::example_tests!(example);
+    lib::generator_tests!();
+}
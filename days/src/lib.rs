@@ -0,0 +1,26 @@
+use lib::Registry;
+
+/// Returns the registry of all days in the workspace.
+pub fn registry() -> Registry {
+    Registry::new(vec![
+        day_01::day(),
+        day_02::day(),
+        day_03::day(),
+        day_04::day(),
+        day_05::day(),
+        day_06::day(),
+        day_07::day(),
+        day_08::day(),
+        day_09::day(),
+        day_10::day(),
+        day_11::day(),
+        day_12::day(),
+        day_13::day(),
+        day_14::day(),
+        day_15::day(),
+        day_16::day(),
+        day_18::day(),
+        day_19::day(),
+        day_20::day(),
+    ])
+}
@@ -0,0 +1,156 @@
+use crate::{Part, EXAMPLES_DIR};
+use core::fmt;
+use std::{fs, io, path::Path};
+
+/// Confirmed answers of a single input file of a day.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answer {
+    fn parse(line: &str) -> Self {
+        let mut split = line.split_ascii_whitespace();
+        let input = split.next().unwrap().to_owned();
+
+        let mut next = || split.next().filter(|&v| v != "-").map(ToOwned::to_owned);
+        let part1 = next();
+        let part2 = next();
+
+        Self {
+            input,
+            part1,
+            part2,
+        }
+    }
+
+    pub fn part(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part1 = self.part1.as_deref().unwrap_or("-");
+        let part2 = self.part2.as_deref().unwrap_or("-");
+        write!(f, "{} {part1} {part2}", self.input)
+    }
+}
+
+/// Recorded answers of a day, as stored in its `answers.txt`.
+///
+/// Every line holds an input file path relative to the day directory
+/// followed by the answers of part 1 and part 2. Unknown answers are
+/// written as `-` and lines starting with `#` are ignored.
+///
+/// ```text
+/// # input      part1 part2
+/// input.txt    56506 56017
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers(Vec<Answer>);
+
+impl Answers {
+    pub const FILE: &'static str = "answers.txt";
+
+    pub fn parse(raw: &str) -> Self {
+        Self(
+            raw.lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .map(Answer::parse)
+                .collect(),
+        )
+    }
+
+    /// Loads the answers file from the given day directory. A missing
+    /// file results in an empty set of answers.
+    pub fn load(dir: &Path) -> Self {
+        fs::read_to_string(dir.join(Self::FILE))
+            .map(|raw| Self::parse(&raw))
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, input: &str) -> Option<&Answer> {
+        self.0.iter().find(|a| a.input == input)
+    }
+
+    /// The parts to run for the given input. Examples often only cover one
+    /// of the parts, so only the parts recorded for them are run.
+    pub fn parts(&self, input: &str) -> Vec<Part> {
+        match self.get(input) {
+            Some(answer) if input.starts_with(EXAMPLES_DIR) => Part::ALL
+                .into_iter()
+                .filter(|&p| answer.part(p).is_some())
+                .collect(),
+            _ => Part::ALL.to_vec(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Answer> {
+        self.0.iter()
+    }
+
+    /// Adds the given answers to the answers file in the given directory,
+    /// filling in parts which are still unknown but never changing recorded
+    /// ones. All other lines, including comments, are kept as they are.
+    pub fn merge(dir: &Path, answers: &[Answer]) -> io::Result<()> {
+        let file = dir.join(Self::FILE);
+        let mut lines: Vec<String> = match fs::read_to_string(&file) {
+            Ok(raw) => raw.lines().map(ToOwned::to_owned).collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => return Err(err),
+        };
+
+        for answer in answers {
+            let line = lines
+                .iter()
+                .position(|l| l.split_ascii_whitespace().next() == Some(answer.input.as_str()));
+            let recorded = line.map(|i| Answer::parse(&lines[i]));
+
+            let merged = Answer {
+                input: answer.input.clone(),
+                part1: recorded
+                    .as_ref()
+                    .and_then(|a| a.part1.clone())
+                    .or_else(|| answer.part1.clone()),
+                part2: recorded
+                    .as_ref()
+                    .and_then(|a| a.part2.clone())
+                    .or_else(|| answer.part2.clone()),
+            };
+
+            match line {
+                Some(i) => lines[i] = merged.to_string(),
+                None => lines.push(merged.to_string()),
+            }
+        }
+
+        fs::write(file, lines.join("\n") + "\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# comment\ninput.txt 12 abc\n\nexample.txt - 3\n");
+        assert_eq!(answers.iter().count(), 2);
+
+        let input = answers.get("input.txt").unwrap();
+        assert_eq!(input.part(Part::One), Some("12"));
+        assert_eq!(input.part(Part::Two), Some("abc"));
+
+        let example = answers.get("example.txt").unwrap();
+        assert_eq!(example.part(Part::One), None);
+        assert_eq!(example.part(Part::Two), Some("3"));
+        assert_eq!(example.to_string(), "example.txt - 3");
+    }
+}
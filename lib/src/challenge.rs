@@ -0,0 +1,342 @@
+use crate::Part;
+
+/// Phrases in the introduction of a block which indicate that it shows an
+/// earlier example again, an excerpt of it or a rendering derived from it.
+const REFERS_BACK: &[&str] = &[
+    "above",
+    "again",
+    "before",
+    "earlier",
+    "same example",
+    "one of the",
+];
+
+/// A worked example of the puzzle text together with the answers quoted
+/// for it.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    pub fn part(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+}
+
+/// The title of the puzzle, taken from the `--- Day 1: Trebuchet?! ---`
+/// heading on the first line of its text.
+pub fn challenge_title(challenge: &str) -> Option<&str> {
+    let heading = challenge.lines().next()?.trim();
+    let title = heading.strip_prefix("---")?.strip_suffix("---")?.trim();
+    (!title.is_empty()).then_some(title)
+}
+
+/// Extracts the worked examples from the puzzle text of a day.
+///
+/// Example inputs are the blocks following a paragraph which mentions an
+/// example and ends with a colon. The answer of a part is the last number
+/// quoted before the question of the part and is assigned to the example
+/// announcing it, or else to the example shown last before it. Examples
+/// without any quoted answer are dropped.
+pub fn extract_examples(challenge: &str) -> Vec<Example> {
+    let challenge = challenge.replace("\r\n", "\n");
+    let mut examples: Vec<Example> = vec![];
+    let mut previous = None;
+
+    for (section, part) in challenge.split("--- Part Two ---").zip(Part::ALL) {
+        let paragraphs: Vec<_> = paragraphs(section)
+            .into_iter()
+            .filter(|p| !p[0].starts_with("--- Day") && !p[0].starts_with("Your puzzle answer"))
+            .collect();
+
+        // Pairs of the index of the example and the index of its intro.
+        let mut candidates = vec![];
+        let mut i = 0;
+        while i < paragraphs.len() {
+            let intro = i;
+            i += 1;
+            if !is_intro(&paragraphs[intro]) {
+                continue;
+            }
+
+            let start = i;
+            while i < paragraphs.len() && is_input(&paragraphs[i]) {
+                i += 1;
+            }
+            let block = &paragraphs[start..i];
+            if block.is_empty() || block[0][0].ends_with(':') {
+                continue;
+            }
+
+            let input = block
+                .iter()
+                .map(|p| p.join("\n"))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+
+            let idx = match examples.iter().position(|e| is_excerpt(&input, &e.input)) {
+                Some(idx) => idx,
+                None if refers_back(&paragraphs[intro]) => continue,
+                None => {
+                    examples.push(Example {
+                        input,
+                        ..Default::default()
+                    });
+                    examples.len() - 1
+                }
+            };
+            candidates.push((idx, intro));
+        }
+
+        let Some((answer, at)) = quoted_answer(&paragraphs) else {
+            continue;
+        };
+
+        let idx = candidates
+            .iter()
+            .find(|&&(_, intro)| numbers(&paragraphs[intro].join(" ")).any(|n| n == answer))
+            .or_else(|| candidates.iter().rev().find(|&&(_, intro)| intro < at))
+            .map(|&(idx, _)| idx)
+            .or(previous);
+
+        if let Some(idx) = idx {
+            examples[idx].set(part, answer);
+            previous = Some(idx);
+        }
+    }
+
+    examples.retain(|e| e.part1.is_some() || e.part2.is_some());
+    examples
+}
+
+/// Groups the lines of the text into paragraphs separated by blank lines.
+fn paragraphs(text: &str) -> Vec<Vec<&str>> {
+    let mut res = vec![];
+    let mut current = vec![];
+
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                res.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        res.push(current);
+    }
+
+    res
+}
+
+fn last_sentence(paragraph: &[&str]) -> String {
+    let text = paragraph.join(" ");
+    let start = [". ", "! ", "? "]
+        .iter()
+        .filter_map(|s| text.rfind(s).map(|i| i + s.len()))
+        .max()
+        .unwrap_or(0);
+    text[start..].to_lowercase()
+}
+
+/// Whether the paragraph is a sentence of the puzzle text rather than a
+/// line of some example.
+fn is_prose(paragraph: &[&str]) -> bool {
+    let line = paragraph[0].trim_end();
+    let words = line
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| c.is_ascii_punctuation()))
+        .filter(|w| !w.is_empty() && w.chars().all(|c| c.is_ascii_lowercase()))
+        .count();
+
+    line.ends_with(['.', ':', '!', '?', ')']) && words >= 3
+}
+
+fn is_intro(paragraph: &[&str]) -> bool {
+    paragraph.last().unwrap().trim_end().ends_with(':')
+        && last_sentence(paragraph).contains("example")
+}
+
+fn is_input(paragraph: &[&str]) -> bool {
+    !is_prose(paragraph)
+        && !is_intro(paragraph)
+        && !paragraph.iter().any(|l| l.starts_with(char::is_whitespace))
+}
+
+fn refers_back(intro: &[&str]) -> bool {
+    let sentence = last_sentence(intro);
+    REFERS_BACK.iter().any(|p| sentence.contains(p))
+}
+
+/// Whether all lines of the input also appear in the given example.
+fn is_excerpt(input: &str, example: &str) -> bool {
+    let lines: Vec<_> = example.lines().collect();
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .all(|l| lines.contains(&l))
+}
+
+/// The answer quoted for the example of a section, which is the last
+/// number of the last paragraph mentioning one before the question,
+/// together with the index of that paragraph. There is none if that
+/// paragraph only mentions numbers of hypothetical sentences.
+fn quoted_answer(paragraphs: &[Vec<&str>]) -> Option<(String, usize)> {
+    let question = paragraphs
+        .iter()
+        .rposition(|p| is_prose(p) && p.iter().any(|l| l.contains('?')))?;
+
+    paragraphs[..question]
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, p)| is_prose(p))
+        .find_map(|(i, p)| {
+            let text = p.join(" ");
+            numbers(&text).next()?;
+
+            // Numbers in parentheses usually show how the answer was
+            // calculated, so they are only used as a last resort.
+            let text = strip_hypotheticals(&text);
+            let answer = numbers(&strip_parentheses(&text))
+                .last()
+                .or_else(|| numbers(&text).last())
+                .map(|n| (n.to_owned(), i));
+            Some(answer)
+        })
+        .flatten()
+}
+
+/// Drops the sentences stating what the answer would be under other
+/// conditions, e.g. "if each row were 10 times larger, the sum would be
+/// 1030", as their numbers are not the answer of the example.
+fn strip_hypotheticals(text: &str) -> String {
+    text.split_inclusive(['.', '!', '?'])
+        .filter(|s| {
+            !s.split("would be ")
+                .skip(1)
+                .any(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+        })
+        .collect()
+}
+
+fn strip_parentheses(line: &str) -> String {
+    let mut depth = 0;
+    line.chars()
+        .filter(|&c| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth = (depth - 1).max(0),
+                _ => return depth == 0,
+            }
+            false
+        })
+        .collect()
+}
+
+fn numbers(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_ascii_digit() && c != '-')
+        .filter(|v| v.parse::<i128>().is_ok())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CHALLENGE: &str = "\
+--- Day 1: Test ---
+
+The numbers in your puzzle input need to be summed up. For example:
+
+1 2
+3 4
+
+Consider again the example from before:
+
+3 4
+
+Adding all of these numbers produces 10 (1 + 2 + 3 + 4).
+
+What is the sum of all numbers?
+
+Your puzzle answer was 1234.
+--- Part Two ---
+
+Now, the numbers have to be multiplied. Here is a larger example:
+
+1 2
+
+3 4 5
+
+In this larger example, the product of all numbers is 120.
+
+What is the product of all numbers?
+";
+
+    #[test]
+    fn test_challenge_title() {
+        assert_eq!(challenge_title(CHALLENGE), Some("Day 1: Test"));
+        assert_eq!(challenge_title(""), None);
+    }
+
+    #[test]
+    fn test_hypothetical_answers() {
+        let challenge = "\
+Each number in the example counts ten times. For example:
+
+1 2
+
+If you add up the numbers that would be counted, you get 30.
+
+What is the total?
+--- Part Two ---
+
+Now, each number counts a million times.
+
+(If each number counted 100 times instead, the total would be 300.)
+
+What is the total now?
+";
+        assert_eq!(
+            extract_examples(challenge),
+            vec![Example {
+                input: "1 2".into(),
+                part1: Some("30".into()),
+                part2: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_extract_examples() {
+        assert_eq!(
+            extract_examples(CHALLENGE),
+            vec![
+                Example {
+                    input: "1 2\n3 4".into(),
+                    part1: Some("10".into()),
+                    part2: None,
+                },
+                Example {
+                    input: "1 2\n\n3 4 5".into(),
+                    part1: None,
+                    part2: Some("120".into()),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{Answer, Answers, Day, Example, Input, Part};
+use std::{fs, io, path::Path};
+
+/// Directory inside of a day directory holding the example inputs.
+pub const EXAMPLES_DIR: &str = "examples";
+
+impl Input {
+    /// All example inputs of the given day, sorted by file name.
+    pub fn examples(day: &Day) -> Vec<Self> {
+        example_files(&day.dir())
+            .iter()
+            .map(|f| Self::day_file(day, &format!("{EXAMPLES_DIR}/{f}")))
+            .collect()
+    }
+}
+
+fn example_files(dir: &Path) -> Vec<String> {
+    let Ok(entries) = dir.join(EXAMPLES_DIR).read_dir() else {
+        return vec![];
+    };
+
+    let mut files: Vec<_> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .filter(|f| f.ends_with(".txt"))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Writes the given examples as fixtures into the given day directory and
+/// returns the paths of their files relative to it.
+///
+/// Examples matching the content of an existing example file reuse it,
+/// all others are written to the next free `examples/example[_<n>].txt`.
+/// Their answers are added to `answers.txt`, filling in parts which are
+/// still unknown but never changing recorded ones.
+pub fn write_examples(dir: &Path, examples: &[Example]) -> io::Result<Vec<String>> {
+    let examples_dir = dir.join(EXAMPLES_DIR);
+    fs::create_dir_all(&examples_dir)?;
+
+    let mut files: Vec<_> = example_files(dir)
+        .into_iter()
+        .map(|f| {
+            let input = Input::file(examples_dir.join(&f))
+                .read()
+                .unwrap_or_default();
+            (f, input)
+        })
+        .collect();
+
+    let mut res = vec![];
+    let mut answers = vec![];
+    for example in examples {
+        let file = match files
+            .iter()
+            .find(|(_, input)| *input == example.input.trim_end())
+        {
+            Some((f, _)) => f.clone(),
+            None => {
+                let f = (1..)
+                    .map(|n| match n {
+                        1 => "example.txt".to_owned(),
+                        n => format!("example_{n}.txt"),
+                    })
+                    .find(|f| files.iter().all(|(e, _)| e != f))
+                    .unwrap();
+                fs::write(examples_dir.join(&f), format!("{}\n", example.input))?;
+                files.push((f.clone(), example.input.clone()));
+                f
+            }
+        };
+
+        let file = format!("{EXAMPLES_DIR}/{file}");
+        answers.push(Answer {
+            input: file.clone(),
+            part1: example.part1.clone(),
+            part2: example.part2.clone(),
+        });
+        res.push(file);
+    }
+
+    Answers::merge(dir, &answers)?;
+    Ok(res)
+}
+
+/// Rewrites the [`example_tests!`](crate::example_tests) invocation in the
+/// `src/lib.rs` of the given day directory to generate one test case per
+/// example file, so that newly written examples are tested as well.
+pub fn write_example_tests(dir: &Path) -> io::Result<()> {
+    let names: Vec<_> = example_files(dir)
+        .iter()
+        .filter_map(|f| f.strip_suffix(".txt"))
+        .filter(|n| n.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+        .filter(|n| n.starts_with(|c: char| c.is_ascii_alphabetic()))
+        .map(str::to_owned)
+        .collect();
+
+    let file = dir.join("src").join("lib.rs");
+    let source = fs::read_to_string(&file)?;
+    let source = set_example_tests(&source, &names).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "no example_tests! invocation in src/lib.rs",
+        )
+    })?;
+    fs::write(file, source)
+}
+
+/// Replaces the arguments of the `example_tests!` invocation in the source
+/// with the given names.
+fn set_example_tests(source: &str, names: &[String]) -> Option<String> {
+    let call = "example_tests!(";
+    let start = source.find(call)? + call.len();
+    let end = start + source[start..].find(')')?;
+    Some(format!(
+        "{}{}{}",
+        &source[..start],
+        names.join(", "),
+        &source[end..]
+    ))
+}
+
+/// Runs the given example input and asserts the answers recorded for it.
+/// Used by [`example_tests!`](crate::example_tests).
+pub fn test_example(day: Day, file: &str, input: &str, answers: &str) {
+    let answers = Answers::parse(answers);
+    let answer = answers
+        .get(file)
+        .unwrap_or_else(|| panic!("no answers recorded for {file}"));
+
+    let input = Input::embedded(file, input).read().unwrap();
+    let parsed = day.parse(&input);
+
+    for part in Part::ALL {
+        if let Some(expected) = answer.part(part) {
+            assert_eq!(day.part(&parsed, part), expected, "part {part} of {file}");
+        }
+    }
+}
+
+/// Generates one test case per given example of the day. Each example
+/// `<name>` is read from `examples/<name>.txt` and checked against the
+/// answers recorded for it in `answers.txt`. The list is kept up to date
+/// by `aoc examples`, see [`write_example_tests`].
+///
+/// ```text
+/// #[cfg(test)]
+/// mod test {
+///     lib::example_tests!(example, example_2);
+/// }
+/// ```
+// `crate::day()` deliberately refers to the day crate invoking the macro.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! example_tests {
+    ($($name: ident),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::test_example(
+                    crate::day(),
+                    concat!("examples/", stringify!($name), ".txt"),
+                    include_str!(concat!(
+                        env!("CARGO_MANIFEST_DIR"),
+                        "/examples/",
+                        stringify!($name),
+                        ".txt"
+                    )),
+                    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt")),
+                );
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_set_example_tests() {
+        let source = "#[cfg(test)]\nmod test {\n    lib::example_tests!();\n}\n";
+        let names = ["example".to_owned(), "example_2".to_owned()];
+        assert_eq!(
+            set_example_tests(source, &names).unwrap(),
+            "#[cfg(test)]\nmod test {\n    lib::example_tests!(example, example_2);\n}\n"
+        );
+
+        let source = "    lib::example_tests!(\n        example,\n        other,\n    );\n";
+        assert_eq!(
+            set_example_tests(source, &names[..1]).unwrap(),
+            "    lib::example_tests!(example);\n"
+        );
+
+        assert!(set_example_tests("mod test {}", &names).is_none());
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{Day, Rng};
+
+/// Produces random but valid puzzle inputs of a day, which allows to
+/// measure how its solution scales with the size of the input. A day
+/// opts into it via
+///
+/// ```ignore
+/// lib::register!(Day10, generator = Day10);
+/// ```
+pub trait Generator {
+    /// Sizes the runner measures by default, ordered ascending.
+    const SIZES: &'static [usize] = &[10, 20, 40, 80, 160];
+
+    /// Generates an input of the given size, whose meaning is up to the
+    /// day, e.g. the side length of a grid or the number of lines.
+    fn generate(size: usize, rng: &mut Rng) -> String;
+}
+
+/// Solves inputs generated from a couple of seeds for the smallest sizes of
+/// the day, which must have a [`Generator`]. Used by [`generator_tests`].
+pub fn test_generator(day: Day) {
+    let sizes = day.sizes().expect("day has no generator");
+
+    for &size in sizes.iter().take(2) {
+        for seed in 0..2 {
+            let input = day.generate(size, seed).unwrap();
+            assert!(!input.is_empty(), "empty input of size {size}");
+            day.solve(&input);
+        }
+    }
+}
+
+/// Generates a test solving random inputs of the day invoking the macro,
+/// see [`test_generator`].
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod test {
+///     lib::generator_tests!();
+/// }
+/// ```
+// `crate::day()` deliberately refers to the day crate invoking the macro.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! generator_tests {
+    () => {
+        #[test]
+        fn generated() {
+            $crate::test_generator(crate::day());
+        }
+    };
+}
@@ -0,0 +1,177 @@
+use crate::Day;
+use core::fmt;
+use std::{
+    env,
+    error::Error,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable which, when set, overrides the location of the day
+/// directories. Days are then expected at `$AOC_ROOT/day-XX`.
+pub const ROOT_ENV: &str = "AOC_ROOT";
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(String, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "input file not found: {}", path.display()),
+            InputError::Io(source, err) => write!(f, "failed to read {source}: {err}"),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    File(PathBuf),
+    Stdin,
+    Embedded(String),
+}
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    name: String,
+    source: Source,
+}
+
+impl Input {
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Self {
+            name,
+            source: Source::File(path),
+        }
+    }
+
+    pub fn stdin() -> Self {
+        Self {
+            name: "stdin".into(),
+            source: Source::Stdin,
+        }
+    }
+
+    pub fn embedded(name: &str, input: &str) -> Self {
+        Self {
+            name: name.into(),
+            source: Source::Embedded(input.into()),
+        }
+    }
+
+    /// A file inside of the directory of the given day, e.g. `input.txt`.
+    /// The file keeps its path relative to the day directory as name.
+    pub fn day_file(day: &Day, file: &str) -> Self {
+        Self {
+            name: file.into(),
+            source: Source::File(day.dir().join(file)),
+        }
+    }
+
+    /// Picks the input from the command line arguments:
+    ///
+    /// - `--input <path>` reads the given file,
+    /// - `--stdin` reads from the standard input,
+    /// - `--test` reads `test_input.txt` of the day,
+    /// - otherwise `input.txt` of the day is read.
+    pub fn from_args(day: &Day) -> Self {
+        let args: Vec<_> = env::args().collect();
+
+        if let Some(path) = args
+            .iter()
+            .position(|a| a == "--input")
+            .and_then(|i| args.get(i + 1))
+        {
+            Self::file(path)
+        } else if args.iter().any(|a| a == "--stdin") {
+            Self::stdin()
+        } else if args.iter().any(|a| a == "--test") {
+            Self::day_file(day, "test_input.txt")
+        } else {
+            Self::day_file(day, "input.txt")
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The path of the input file, if the input is read from one.
+    pub fn path(&self) -> Option<&Path> {
+        match &self.source {
+            Source::File(path) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Reads the input, normalizes CRLF line endings and strips trailing
+    /// whitespace.
+    pub fn read(&self) -> Result<String, InputError> {
+        let raw = match &self.source {
+            Source::File(path) => fs::read_to_string(path).map_err(|err| match err.kind() {
+                io::ErrorKind::NotFound => InputError::NotFound(path.clone()),
+                _ => InputError::Io(path.display().to_string(), err),
+            })?,
+            Source::Stdin => {
+                let mut raw = String::new();
+                io::stdin()
+                    .read_to_string(&mut raw)
+                    .map_err(|err| InputError::Io("stdin".into(), err))?;
+                raw
+            }
+            Source::Embedded(raw) => raw.clone(),
+        };
+
+        Ok(normalize(&raw))
+    }
+}
+
+/// Resolves the directory of a day, either below [`ROOT_ENV`] if it is set
+/// or at the given manifest directory of the day crate.
+pub fn day_dir(name: &str, manifest_dir: &str) -> PathBuf {
+    match env::var_os(ROOT_ENV) {
+        Some(root) => Path::new(&root).join(name),
+        None => PathBuf::from(manifest_dir),
+    }
+}
+
+fn normalize(raw: &str) -> String {
+    raw.replace("\r\n", "\n").trim_end().to_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a\r\nb\r\n\r\n"), "a\nb");
+        assert_eq!(normalize("a\nb\n"), "a\nb");
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = Input::file("does/not/exist.txt").read().unwrap_err();
+        assert!(matches!(err, InputError::NotFound(_)));
+        assert_eq!(err.to_string(), "input file not found: does/not/exist.txt");
+    }
+
+    #[test]
+    fn test_embedded() {
+        let input = Input::embedded("example", "1 2\r\n3 4\r\n");
+        assert_eq!(input.name(), "example");
+        assert_eq!(input.read().unwrap(), "1 2\n3 4");
+    }
+}
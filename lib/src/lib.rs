@@ -1,59 +1,37 @@
+mod answers;
+mod challenge;
 mod direction;
+mod example;
+mod generator;
 mod grid;
+mod input;
+mod lines;
+mod memory;
+mod part;
 mod pos;
 mod pos3d;
+mod prop;
+mod report;
+mod rng;
+mod solution;
+mod trace;
 mod vector;
 
+pub use answers::*;
+pub use challenge::*;
 pub use direction::*;
+pub use example::*;
+pub use generator::*;
 pub use grid::*;
+pub use input::*;
+pub use lines::*;
+pub use memory::*;
+pub use part::*;
 pub use pos::*;
 pub use pos3d::*;
+pub use prop::*;
+pub use report::*;
+pub use rng::*;
+pub use solution::*;
+pub use trace::*;
 pub use vector::*;
-
-#[macro_export]
-macro_rules! read_input {
-    () => {{
-        use std::io::Read;
-
-        let inpt_path = if std::env::args().find(|a| a == "--test").is_some() {
-            "test_input.txt"
-        } else {
-            "input.txt"
-        };
-
-        let path = format!("{}/{}", env!("CARGO_PKG_NAME"), inpt_path);
-        let mut input_file = std::fs::File::open(&path).expect("input file");
-        let mut input = String::new();
-        input_file.read_to_string(&mut input).expect("read input");
-        input.trim_end().to_owned()
-    }};
-}
-
-#[macro_export]
-macro_rules! read_test_input {
-    () => {{
-        use std::io::Read;
-
-        let inpt_path = "test_input.txt";
-
-        let path = format!("{}/{}", env!("CARGO_PKG_NAME"), inpt_path);
-        let mut input_file = std::fs::File::open(&path).expect("input file");
-        let mut input = String::new();
-        input_file.read_to_string(&mut input).expect("read input");
-        input.trim_end().to_owned()
-    }};
-}
-
-#[macro_export]
-macro_rules! p1 {
-    ($p: expr) => {
-        println!("Part 1 Solution: {}", $p);
-    };
-}
-
-#[macro_export]
-macro_rules! p2 {
-    ($p: expr) => {
-        println!("Part 2 Solution: {}", $p);
-    };
-}
@@ -0,0 +1,100 @@
+use std::{cell::Cell, slice, str::Split};
+
+thread_local! {
+    static CURRENT: Cell<Option<(*const u8, usize)>> = const { Cell::new(None) };
+}
+
+/// Iterator over the lines of an input returned by [`lines`].
+#[derive(Clone)]
+pub struct Lines<'a> {
+    input: &'a str,
+    split: Split<'a, char>,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        track(self.split.next())
+    }
+}
+
+impl DoubleEndedIterator for Lines<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        track(self.split.next_back())
+    }
+}
+
+impl Drop for Lines<'_> {
+    /// Forgets the current line if it belongs to this input, which may not
+    /// be borrowed any longer afterwards.
+    fn drop(&mut self) {
+        let range = self.input.as_bytes().as_ptr_range();
+        if CURRENT
+            .get()
+            .is_some_and(|(ptr, _)| (range.start..=range.end).contains(&ptr))
+        {
+            clear_line();
+        }
+    }
+}
+
+/// Records the given line as current one. Only its address is kept, to
+/// keep the overhead per line low.
+fn track(line: Option<&str>) -> Option<&str> {
+    CURRENT.set(line.map(|l| (l.as_ptr(), l.len())));
+    line
+}
+
+/// Splits the input into lines like `input.split('\n')` while remembering
+/// the line currently being processed, so that a panic while parsing can
+/// be traced back to the offending line via [`current_line`].
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines {
+        input,
+        split: input.split('\n'),
+    }
+}
+
+/// The line most recently yielded by [`lines`] on this thread, as long as
+/// its iteration has neither finished nor been dropped yet.
+pub fn current_line() -> Option<String> {
+    CURRENT.get().map(|(ptr, len)| {
+        // SAFETY: The line is forgotten when its iterator is dropped, so it
+        // still points into the input borrowed by the iterator.
+        let bytes = unsafe { slice::from_raw_parts(ptr, len) };
+        String::from_utf8_lossy(bytes).into_owned()
+    })
+}
+
+/// Forgets the current line, e.g. before solving the next part.
+pub fn clear_line() {
+    CURRENT.set(None);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_current_line() {
+        clear_line();
+        let mut it = lines("a\nb\nc");
+        assert_eq!(current_line(), None);
+
+        it.next();
+        it.next();
+        assert_eq!(current_line().as_deref(), Some("b"));
+
+        assert_eq!(it.collect::<Vec<_>>(), vec!["c"]);
+        assert_eq!(current_line(), None);
+
+        let input = String::from("a\nb");
+        let mut it = lines(&input);
+        it.next_back();
+        assert_eq!(current_line().as_deref(), Some("b"));
+
+        drop(it);
+        assert_eq!(current_line(), None);
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    ptr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static LIMIT: AtomicUsize = AtomicUsize::new(usize::MAX);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static USED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator counting the allocations made through it, which a
+/// binary opts into with
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: lib::Counting = lib::Counting;
+/// ```
+///
+/// Allocations exceeding the limit set by [`set_memory_limit`] fail, which
+/// aborts the process with the usual "memory allocation of N bytes failed"
+/// message.
+pub struct Counting;
+
+/// Heap usage of the code measured by [`measure_alloc`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: usize,
+    /// Total number of bytes allocated, of which reallocations count the
+    /// growth only.
+    pub bytes: usize,
+    /// Maximum number of bytes held at once on top of those held already
+    /// before.
+    pub peak: usize,
+}
+
+/// Sets the maximum number of bytes of heap memory in use at once.
+pub fn set_memory_limit(bytes: usize) {
+    LIMIT.store(bytes, Ordering::Relaxed);
+}
+
+/// Calls the given function and returns the heap usage it caused, which
+/// is only counted if [`Counting`] is the global allocator. The counters
+/// are global, so allocations of other threads running at the same time
+/// are included as well.
+pub fn measure_alloc<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let used = USED.load(Ordering::Relaxed);
+    PEAK.store(used, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+
+    let res = f();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(used),
+    };
+    (res, stats)
+}
+
+fn reserve(size: usize) -> bool {
+    let used = USED.fetch_add(size, Ordering::Relaxed).saturating_add(size);
+    if used > LIMIT.load(Ordering::Relaxed) {
+        USED.fetch_sub(size, Ordering::Relaxed);
+        return false;
+    }
+
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    PEAK.fetch_max(used, Ordering::Relaxed);
+    true
+}
+
+fn release(size: usize) {
+    USED.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if !reserve(layout.size()) {
+            return ptr::null_mut();
+        }
+        let ptr = System.alloc(layout);
+        if ptr.is_null() {
+            release(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if !reserve(layout.size()) {
+            return ptr::null_mut();
+        }
+        let ptr = System.alloc_zeroed(layout);
+        if ptr.is_null() {
+            release(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        release(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let grow = new_size.saturating_sub(layout.size());
+        if !reserve(grow) {
+            return ptr::null_mut();
+        }
+
+        let new = System.realloc(ptr, layout, new_size);
+        if new.is_null() {
+            release(grow);
+        } else {
+            release(layout.size().saturating_sub(new_size));
+        }
+        new
+    }
+}
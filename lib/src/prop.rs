@@ -0,0 +1,264 @@
+use crate::Rng;
+use std::{
+    env,
+    fmt::Debug,
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+};
+
+/// Environment variable overriding the seed the cases of [`check`] are
+/// generated from, e.g. to explore other cases than the usual ones.
+pub const SEED_ENV: &str = "PROP_SEED";
+
+/// Upper bound of shrinking steps, in case a value keeps shrinking.
+const MAX_SHRINKS: usize = 10_000;
+
+/// Source of the random cases of a property test, which also knows how to
+/// simplify a case so that a failure can be reduced to a minimal one.
+pub trait Gen {
+    type Value: Clone + Debug;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Simpler variants of the given value, the most aggressive first.
+    fn shrink(&self, _value: &Self::Value) -> Vec<Self::Value> {
+        vec![]
+    }
+}
+
+/// Generator of numbers within a range, see [`int`].
+pub struct Int(Range<usize>);
+
+/// Numbers within the given range, shrinking towards its start.
+pub fn int(range: Range<usize>) -> Int {
+    Int(range)
+}
+
+impl Gen for Int {
+    type Value = usize;
+
+    fn generate(&self, rng: &mut Rng) -> usize {
+        rng.range(self.0.clone())
+    }
+
+    fn shrink(&self, &value: &usize) -> Vec<usize> {
+        let start = self.0.start;
+        let mut res = vec![start, start + (value - start) / 2, value.saturating_sub(1)];
+        res.retain(|&v| v >= start && v < value);
+        res.dedup();
+        res
+    }
+}
+
+/// Generator of one of a fixed set of values, see [`one_of`].
+pub struct OneOf<T>(Vec<T>);
+
+/// One of the given values, shrinking towards the first ones.
+pub fn one_of<T: Clone + Debug + PartialEq>(items: &[T]) -> OneOf<T> {
+    assert!(!items.is_empty(), "no items to pick from");
+    OneOf(items.to_vec())
+}
+
+impl<T: Clone + Debug + PartialEq> Gen for OneOf<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        rng.pick(&self.0).clone()
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        let idx = self.0.iter().position(|i| i == value).unwrap_or(0);
+        self.0[..idx].to_vec()
+    }
+}
+
+/// Generator of vectors, see [`vec_of`].
+pub struct VecOf<G> {
+    elem: G,
+    len: Range<usize>,
+}
+
+/// Vectors of a length within the given range, shrinking by removing
+/// elements and by shrinking the elements themselves.
+pub fn vec_of<G: Gen>(elem: G, len: Range<usize>) -> VecOf<G> {
+    VecOf { elem, len }
+}
+
+impl<G: Gen> Gen for VecOf<G> {
+    type Value = Vec<G::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let len = rng.range(self.len.clone());
+        (0..len).map(|_| self.elem.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let min = self.len.start;
+        let mut res = vec![];
+
+        if value.len() / 2 >= min && value.len() > 1 {
+            res.push(value[..value.len() / 2].to_vec());
+            res.push(value[value.len() / 2..].to_vec());
+        }
+        if value.len() > min {
+            for i in 0..value.len() {
+                let mut v = value.clone();
+                v.remove(i);
+                res.push(v);
+            }
+        }
+        for (i, elem) in value.iter().enumerate() {
+            for shrunk in self.elem.shrink(elem) {
+                let mut v = value.clone();
+                v[i] = shrunk;
+                res.push(v);
+            }
+        }
+
+        res
+    }
+}
+
+macro_rules! tuple_gen {
+    ($($g: ident $v: ident $i: tt),+) => {
+        impl<$($g: Gen),+> Gen for ($($g,)+) {
+            type Value = ($($g::Value,)+);
+
+            fn generate(&self, rng: &mut Rng) -> Self::Value {
+                ($(self.$i.generate(rng),)+)
+            }
+
+            fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+                let mut res = vec![];
+                $(
+                    for shrunk in self.$i.shrink(&value.$i) {
+                        let mut v = value.clone();
+                        v.$i = shrunk;
+                        res.push(v);
+                    }
+                )+
+                res
+            }
+        }
+    };
+}
+
+tuple_gen!(A a 0, B b 1);
+tuple_gen!(A a 0, B b 1, C c 2);
+tuple_gen!(A a 0, B b 1, C c 2, D d 3);
+
+/// Compares the answer of a fast implementation with the one of a naive
+/// reference implementation, to be returned from a property.
+pub fn equal<T: PartialEq + Debug>(fast: T, naive: T) -> Result<(), String> {
+    match fast == naive {
+        true => Ok(()),
+        false => Err(format!("fast {fast:?} != naive {naive:?}")),
+    }
+}
+
+fn holds<V>(prop: &impl Fn(&V) -> Result<(), String>, value: &V) -> Result<(), String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| prop(value))) {
+        Ok(res) => res,
+        Err(err) => Err(err
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| err.downcast_ref::<&str>().map(|s| s.to_string()))
+            .map_or_else(|| "panicked".into(), |msg| format!("panicked: {msg}"))),
+    }
+}
+
+/// A case a property does not hold for, shrunk as far as possible.
+#[derive(Debug)]
+pub struct Failure<V> {
+    /// Index of the generated case which failed first.
+    pub case: usize,
+    pub value: V,
+    pub error: String,
+    pub shrinks: usize,
+}
+
+/// Tries to find a case of the generator the property does not hold for,
+/// either because it returns an error or panics, within the given number
+/// of cases generated from the seed.
+pub fn falsify<G: Gen>(
+    cases: usize,
+    seed: u64,
+    gen: &G,
+    prop: impl Fn(&G::Value) -> Result<(), String>,
+) -> Option<Failure<G::Value>> {
+    let mut rng = Rng::new(seed);
+
+    for case in 0..cases {
+        let value = gen.generate(&mut rng);
+        let Err(error) = holds(&prop, &value) else {
+            continue;
+        };
+
+        let mut failure = Failure {
+            case,
+            value,
+            error,
+            shrinks: 0,
+        };
+        'shrink: while failure.shrinks < MAX_SHRINKS {
+            for candidate in gen.shrink(&failure.value) {
+                if let Err(error) = holds(&prop, &candidate) {
+                    failure.value = candidate;
+                    failure.error = error;
+                    failure.shrinks += 1;
+                    continue 'shrink;
+                }
+            }
+            break;
+        }
+        return Some(failure);
+    }
+
+    None
+}
+
+/// Checks that the property holds for the given number of random cases,
+/// panicking with a minimal failing case otherwise. The cases are the same
+/// on every run unless [`SEED_ENV`] is set.
+pub fn check<G: Gen>(cases: usize, gen: G, prop: impl Fn(&G::Value) -> Result<(), String>) {
+    let seed = env::var(SEED_ENV)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+
+    if let Some(f) = falsify(cases, seed, &gen, prop) {
+        panic!(
+            "property failed for case {} of seed {seed}, shrunk {} times:\n  value: {:?}\n  error: {}",
+            f.case, f.shrinks, f.value, f.error
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shrink() {
+        let failure = falsify(1000, 0, &int(0..1000), |&v| match v < 50 {
+            true => Ok(()),
+            false => Err("too large".into()),
+        });
+        assert_eq!(failure.unwrap().value, 50);
+
+        let gen = vec_of(int(0..100), 0..10);
+        let failure = falsify(1000, 0, &gen, |v| {
+            assert!(v.iter().sum::<usize>() < 10);
+            Ok(())
+        });
+        let failure = failure.unwrap();
+        assert_eq!(failure.value, vec![10]);
+        assert!(failure.error.starts_with("panicked"));
+
+        let gen = (one_of(&['a', 'b', 'c']), int(1..5));
+        let failure = falsify(1000, 0, &gen, |&(c, n)| equal(c == 'c' && n > 2, false));
+        assert_eq!(failure.unwrap().value, ('c', 3));
+
+        assert!(falsify(1000, 0, &int(0..10), |&v| equal(v < 10, true)).is_none());
+    }
+}
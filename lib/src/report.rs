@@ -0,0 +1,142 @@
+use crate::Part;
+use std::{str::FromStr, time::Duration};
+
+/// Output format of [`PartResult`]s.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+    /// Tab separated values, preceded by a header line.
+    Tsv,
+}
+
+impl Format {
+    /// Picks the format from a `--format <fmt>` command line argument,
+    /// falling back to [`Format::Text`].
+    pub fn from_args() -> Self {
+        let args: Vec<_> = std::env::args().collect();
+        args.iter()
+            .position(|a| a == "--format")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|f| f.parse().ok())
+            .unwrap_or_default()
+    }
+
+    pub fn header(self) -> Option<&'static str> {
+        match self {
+            Format::Tsv => Some("day\tinput\tpart\tanswer\ttime_ns"),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            x => Err(format!("invalid format: {x}")),
+        }
+    }
+}
+
+/// The answer of a single part of a day for a given input file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub answer: String,
+    pub time: Duration,
+}
+
+impl PartResult {
+    /// Parses a line written in the [`Format::Tsv`] format.
+    pub fn parse_tsv(line: &str) -> Option<Self> {
+        let mut split = line.split('\t');
+        Some(Self {
+            day: split.next()?.parse().ok()?,
+            input: split.next()?.into(),
+            part: split.next()?.parse().ok()?,
+            answer: split.next()?.into(),
+            time: Duration::from_nanos(split.next()?.parse().ok()?),
+        })
+    }
+
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Text => format!(
+                "Part {} Solution: {} ({:.2?})",
+                self.part, self.answer, self.time
+            ),
+            Format::Json => format!(
+                "{{\"day\": {}, \"input\": {}, \"part\": {}, \"answer\": {}, \"time_ns\": {}}}",
+                self.day,
+                json_string(&self.input),
+                self.part,
+                json_string(&self.answer),
+                self.time.as_nanos()
+            ),
+            Format::Tsv => format!(
+                "{}\t{}\t{}\t{}\t{}",
+                self.day,
+                self.input,
+                self.part,
+                self.answer.replace(['\t', '\n'], " "),
+                self.time.as_nanos()
+            ),
+        }
+    }
+}
+
+pub fn json_string(v: &str) -> String {
+    let mut res = String::with_capacity(v.len() + 2);
+    res.push('"');
+    for c in v.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\t' => res.push_str("\\t"),
+            c if c.is_control() => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let res = PartResult {
+            day: 6,
+            input: "input.txt".into(),
+            part: Part::Two,
+            answer: "42".into(),
+            time: Duration::from_micros(3),
+        };
+
+        assert_eq!(res.format(Format::Text), "Part 2 Solution: 42 (3.00µs)");
+        assert_eq!(
+            res.format(Format::Json),
+            r#"{"day": 6, "input": "input.txt", "part": 2, "answer": "42", "time_ns": 3000}"#
+        );
+        assert_eq!(res.format(Format::Tsv), "6\tinput.txt\t2\t42\t3000");
+        assert_eq!(PartResult::parse_tsv(&res.format(Format::Tsv)), Some(res));
+        assert_eq!(PartResult::parse_tsv(Format::Tsv.header().unwrap()), None);
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c\n"), r#""a\"b\\c\n""#);
+    }
+}
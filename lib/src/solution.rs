@@ -0,0 +1,296 @@
+use crate::{AllocStats, Format, Generator, Input, Part, PartResult, Rng};
+use std::{
+    any::{Any, TypeId},
+    fmt::Display,
+    path::PathBuf,
+    process::exit,
+    time::Instant,
+};
+
+/// A single day's puzzle solution.
+///
+/// The input is parsed once and then handed to both parts, so the
+/// parsing step and each part can be called (and timed) separately.
+pub trait Solution {
+    type Input: 'static;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> impl Display;
+    fn part2(input: &Self::Input) -> impl Display;
+
+    /// Alternative implementations of either part which are expected to
+    /// yield the same answers as [`Solution::part1`] and [`Solution::part2`].
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![]
+    }
+
+    /// Plain text visualizations of the input, e.g. of a grid, which are
+    /// shown in the HTML report.
+    fn renders(_input: &Self::Input) -> Vec<Render> {
+        vec![]
+    }
+}
+
+/// A named alternative implementation of one part of a [`Solution`].
+pub struct Variant<I> {
+    pub name: &'static str,
+    pub part: Part,
+    solve: Box<dyn Fn(&I) -> String>,
+}
+
+impl<I: 'static> Variant<I> {
+    pub fn new<T: Display + 'static>(name: &'static str, part: Part, solve: fn(&I) -> T) -> Self {
+        Self {
+            name,
+            part,
+            solve: Box::new(move |input| solve(input).to_string()),
+        }
+    }
+
+    pub fn solve(&self, input: &I) -> String {
+        (self.solve)(input)
+    }
+}
+
+/// A named plain text visualization produced by [`Solution::renders`].
+pub struct Render {
+    pub name: String,
+    pub text: String,
+}
+
+impl Render {
+    pub fn new(name: impl Into<String>, text: impl Display) -> Self {
+        Self {
+            name: name.into(),
+            text: text.to_string(),
+        }
+    }
+}
+
+/// Parsed input of a [`Day`], opaque to everything but the day itself.
+pub struct Parsed(Box<dyn Any>);
+
+/// Type-erased handle to a [`Solution`] which can be stored in a [`Registry`].
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub name: &'static str,
+    pub manifest_dir: &'static str,
+    parse: fn(&str) -> Parsed,
+    raw_input: bool,
+    part1: fn(&Parsed) -> String,
+    part2: fn(&Parsed) -> String,
+    parse_alloc: fn(&str) -> (Parsed, AllocStats),
+    part_alloc: fn(&Parsed, Part) -> (String, AllocStats),
+    variants: fn() -> Vec<Variant<Parsed>>,
+    renders: fn(&Parsed) -> Vec<Render>,
+    sizes: &'static [usize],
+    generate: Option<fn(usize, &mut Rng) -> String>,
+}
+
+impl Day {
+    pub fn new<S: Solution>(name: &'static str, manifest_dir: &'static str) -> Self {
+        let number = name
+            .trim_start_matches("day-")
+            .parse()
+            .expect("day crate name");
+
+        Self {
+            number,
+            name,
+            manifest_dir,
+            parse: |input| Parsed(Box::new(S::parse(input))),
+            raw_input: TypeId::of::<S::Input>() == TypeId::of::<String>(),
+            part1: |parsed| S::part1(Self::downcast::<S>(parsed)).to_string(),
+            part2: |parsed| S::part2(Self::downcast::<S>(parsed)).to_string(),
+            parse_alloc: |input| {
+                let (input, alloc) = crate::measure_alloc(|| S::parse(input));
+                (Parsed(Box::new(input)), alloc)
+            },
+            part_alloc: |parsed, part| {
+                let input = Self::downcast::<S>(parsed);
+                match part {
+                    Part::One => {
+                        let (answer, alloc) = crate::measure_alloc(|| S::part1(input));
+                        (answer.to_string(), alloc)
+                    }
+                    Part::Two => {
+                        let (answer, alloc) = crate::measure_alloc(|| S::part2(input));
+                        (answer.to_string(), alloc)
+                    }
+                }
+            },
+            variants: || {
+                S::variants()
+                    .into_iter()
+                    .map(|v| Variant {
+                        name: v.name,
+                        part: v.part,
+                        solve: Box::new(move |parsed| v.solve(Self::downcast::<S>(parsed))),
+                    })
+                    .collect()
+            },
+            renders: |parsed| S::renders(Self::downcast::<S>(parsed)),
+            sizes: &[],
+            generate: None,
+        }
+    }
+
+    /// Adds the [`Generator`] of random inputs of this day.
+    pub fn with_generator<G: Generator>(self) -> Self {
+        Self {
+            sizes: G::SIZES,
+            generate: Some(G::generate),
+            ..self
+        }
+    }
+
+    fn downcast<S: Solution>(parsed: &Parsed) -> &S::Input {
+        parsed
+            .0
+            .downcast_ref()
+            .expect("parsed input belongs to another day")
+    }
+
+    /// The directory holding the inputs and answers of this day.
+    pub fn dir(&self) -> PathBuf {
+        crate::day_dir(self.name, self.manifest_dir)
+    }
+
+    pub fn parse(&self, input: &str) -> Parsed {
+        crate::clear_line();
+        (self.parse)(input)
+    }
+
+    pub fn part(&self, parsed: &Parsed, part: Part) -> String {
+        crate::clear_line();
+        match part {
+            Part::One => (self.part1)(parsed),
+            Part::Two => (self.part2)(parsed),
+        }
+    }
+
+    /// Whether the day keeps the raw input as its parsed input, so that all
+    /// of its parsing happens in the parts.
+    pub fn raw_input(&self) -> bool {
+        self.raw_input
+    }
+
+    /// Like [`Day::parse`], but also returns the heap usage of the parser
+    /// itself, which excludes type-erasing its result.
+    pub fn parse_alloc(&self, input: &str) -> (Parsed, AllocStats) {
+        crate::clear_line();
+        (self.parse_alloc)(input)
+    }
+
+    /// Like [`Day::part`], but also returns the heap usage of the solution
+    /// itself, which excludes formatting its answer.
+    pub fn part_alloc(&self, parsed: &Parsed, part: Part) -> (String, AllocStats) {
+        crate::clear_line();
+        (self.part_alloc)(parsed, part)
+    }
+
+    /// Solves the given part and records the answer together with the
+    /// time it took. `input` names the input file the answer belongs to.
+    pub fn run(&self, input: &str, parsed: &Parsed, part: Part) -> PartResult {
+        let start = Instant::now();
+        let answer = self.part(parsed, part);
+        let time = start.elapsed();
+
+        PartResult {
+            day: self.number,
+            input: input.into(),
+            part,
+            answer,
+            time,
+        }
+    }
+
+    pub fn variants(&self) -> Vec<Variant<Parsed>> {
+        (self.variants)()
+    }
+
+    pub fn renders(&self, parsed: &Parsed) -> Vec<Render> {
+        (self.renders)(parsed)
+    }
+
+    /// The default sizes of the inputs generated by [`Day::generate`], if
+    /// the day has a [`Generator`].
+    pub fn sizes(&self) -> Option<&'static [usize]> {
+        self.generate.map(|_| self.sizes)
+    }
+
+    /// Generates a random input of the given size, if the day has a
+    /// [`Generator`]. The same seed always yields the same input.
+    pub fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        self.generate
+            .map(|generate| generate(size, &mut Rng::new(seed)))
+    }
+
+    pub fn solve(&self, input: &str) -> (String, String) {
+        let parsed = self.parse(input);
+        (self.part(&parsed, Part::One), self.part(&parsed, Part::Two))
+    }
+}
+
+/// All registered days, ordered by day number.
+pub struct Registry(Vec<Day>);
+
+impl Registry {
+    pub fn new(mut days: Vec<Day>) -> Self {
+        days.sort_by_key(|d| d.number);
+        Self(days)
+    }
+
+    pub fn get(&self, number: u8) -> Option<&Day> {
+        self.0.iter().find(|d| d.number == number)
+    }
+
+    pub fn last(&self) -> Option<&Day> {
+        self.0.last()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Day> {
+        self.0.iter()
+    }
+}
+
+/// Runs both parts of the given day with the input picked by
+/// [`Input::from_args`] and prints the results in the format passed
+/// via `--format`.
+pub fn run(day: Day) {
+    let format = Format::from_args();
+    let input = Input::from_args(&day);
+
+    let raw = input.read().unwrap_or_else(|err| {
+        eprintln!("{err}");
+        exit(1);
+    });
+
+    if let Some(header) = format.header() {
+        println!("{header}");
+    }
+
+    let parsed = day.parse(&raw);
+    for part in Part::ALL {
+        println!("{}", day.run(input.name(), &parsed, part).format(format));
+    }
+}
+
+/// Exposes the given [`Solution`] as `day()` so that it can be
+/// picked up by the shared registry, optionally along with its
+/// [`Generator`].
+#[macro_export]
+macro_rules! register {
+    ($solution: ty) => {
+        pub fn day() -> $crate::Day {
+            $crate::Day::new::<$solution>(env!("CARGO_PKG_NAME"), env!("CARGO_MANIFEST_DIR"))
+        }
+    };
+    ($solution: ty, generator = $generator: ty) => {
+        pub fn day() -> $crate::Day {
+            $crate::Day::new::<$solution>(env!("CARGO_PKG_NAME"), env!("CARGO_MANIFEST_DIR"))
+                .with_generator::<$generator>()
+        }
+    };
+}
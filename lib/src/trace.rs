@@ -0,0 +1,240 @@
+use std::{
+    fmt,
+    io::Write,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        RwLock,
+    },
+};
+
+/// Whether the tracing macros are compiled in, which is the case for debug
+/// builds and for release builds with the `trace` feature enabled. Otherwise
+/// they expand to nothing, so diagnostics can stay in the solutions.
+pub const TRACING: bool = cfg!(any(debug_assertions, feature = "trace"));
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static DIRECTIVES: RwLock<Vec<Directive>> = RwLock::new(Vec::new());
+
+/// Verbosity of a trace event, from the most to the least important one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Self; 5] = [
+        Self::Error,
+        Self::Warn,
+        Self::Info,
+        Self::Debug,
+        Self::Trace,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|l| l.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("invalid trace level: {s}"))
+    }
+}
+
+/// Maximum level of the events of a target, or of all targets if none is
+/// given.
+#[derive(Debug, PartialEq, Eq)]
+struct Directive {
+    target: Option<String>,
+    level: Level,
+}
+
+impl FromStr for Directive {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (target, level) = match s.split_once('=') {
+            Some((target, level)) => (Some(target), level.parse()?),
+            None => match s.parse() {
+                Ok(level) => (None, level),
+                Err(_) => (Some(s), Level::Trace),
+            },
+        };
+
+        let target = match target.map(target_name) {
+            Some(t) if t.is_empty() => return Err(format!("invalid trace directive: {s}")),
+            target => target,
+        };
+        Ok(Self { target, level })
+    }
+}
+
+/// Name of the target of a module path, so that `day-05`, `day_05::grid`
+/// and `day5` all name the same target `day05`.
+fn target_name(path: &str) -> String {
+    let krate = path.split("::").next().unwrap_or_default();
+    let name: String = krate.chars().filter(|c| c.is_alphanumeric()).collect();
+
+    match name.strip_prefix("day").map(str::parse::<u8>) {
+        Some(Ok(day)) => format!("day{day:02}"),
+        _ => name,
+    }
+}
+
+/// Enables the trace events matching the given comma separated directives,
+/// e.g. `day14=debug`, `info` for all targets or `day10` for all levels of
+/// a single target. Directives of a target take precedence over those of
+/// all targets.
+pub fn set_trace(spec: &str) -> Result<(), String> {
+    let directives = spec
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(str::parse)
+        .collect::<Result<Vec<Directive>, _>>()?;
+
+    ENABLED.store(!directives.is_empty(), Ordering::Relaxed);
+    *DIRECTIVES.write().unwrap() = directives;
+    Ok(())
+}
+
+/// Whether events of the given module path and level are enabled. Used by
+/// [`event!`] and not meant to be called directly.
+#[doc(hidden)]
+pub fn trace_enabled(path: &str, level: Level) -> bool {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return false;
+    }
+
+    let target = target_name(path);
+    let directives = DIRECTIVES.read().unwrap();
+    directives
+        .iter()
+        .filter(|d| d.target.as_ref() == Some(&target))
+        .chain(directives.iter().filter(|d| d.target.is_none()))
+        .next()
+        .is_some_and(|d| level <= d.level)
+}
+
+/// Writes an event to stderr, prefixing every line of it with its target
+/// and level. Used by [`event!`] and not meant to be called directly.
+#[doc(hidden)]
+pub fn trace_log(path: &str, level: Level, args: fmt::Arguments) {
+    let prefix = format!("[{} {level}]", target_name(path));
+    let message = args.to_string();
+
+    let mut stderr = std::io::stderr().lock();
+    for line in message.lines() {
+        let _ = writeln!(stderr, "{prefix} {line}");
+    }
+}
+
+/// Whether the given line of stderr output has been written by
+/// [`trace_log`].
+pub fn is_trace_line(line: &str) -> bool {
+    line.strip_prefix('[')
+        .and_then(|l| l.split_once(']'))
+        .and_then(|(prefix, _)| prefix.split_once(' '))
+        .is_some_and(|(_, level)| level.parse::<Level>().is_ok())
+}
+
+/// Emits a trace event of the given [`Level`] with the target of the
+/// calling crate, if enabled via [`set_trace`].
+///
+/// ```ignore
+/// lib::event!(lib::Level::Info, "loop of length {}", len);
+/// ```
+#[macro_export]
+macro_rules! event {
+    ($level: expr, $($arg: tt)+) => {
+        if $crate::TRACING && $crate::trace_enabled(module_path!(), $level) {
+            $crate::trace_log(module_path!(), $level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg: tt)+) => { $crate::event!($crate::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg: tt)+) => { $crate::event!($crate::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg: tt)+) => { $crate::event!($crate::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg: tt)+) => { $crate::event!($crate::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg: tt)+) => { $crate::event!($crate::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_directives() {
+        assert_eq!(target_name("day_14::grid"), "day14");
+        assert_eq!(target_name("day-05"), "day05");
+        assert_eq!(target_name("day5"), "day05");
+
+        assert_eq!(
+            "day14=debug".parse(),
+            Ok(Directive {
+                target: Some("day14".into()),
+                level: Level::Debug
+            })
+        );
+        assert_eq!(
+            "info".parse(),
+            Ok(Directive {
+                target: None,
+                level: Level::Info
+            })
+        );
+        assert!("day14=loud".parse::<Directive>().is_err());
+
+        set_trace("warn,day_14=debug").unwrap();
+        assert!(trace_enabled("day_14", Level::Debug));
+        assert!(!trace_enabled("day_14", Level::Trace));
+        assert!(trace_enabled("day_10", Level::Warn));
+        assert!(!trace_enabled("day_10", Level::Info));
+        set_trace("").unwrap();
+        assert!(!trace_enabled("day_14", Level::Error));
+
+        assert!(is_trace_line("[day14 DEBUG] cycle of 7 spins"));
+        assert!(!is_trace_line("panicked at src/lib.rs:1:1:"));
+    }
+}
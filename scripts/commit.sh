@@ -7,8 +7,8 @@ function abort {
     exit 1
 }
 
-grep "TODO:" "$current_day/src/main.rs" && {
-    abort "main.rs still contains TODOs!"
+grep -r "TODO:" "$current_day/src" && {
+    abort "src still contains TODOs!"
 }
 
 [ "$(wc -c < "$current_day/challenge.txt")" == "0" ] && {